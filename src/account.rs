@@ -0,0 +1,288 @@
+use crate::{Error, ExtendedPublicKey, ExtendedSecretKey, PublicKey, SecretKey};
+
+use coins_bip32::path::DerivationPath;
+use coins_bip32::BIP32_HARDEN;
+
+use core::{fmt, str};
+
+/// BIP-44 derivation path of the Fuel coin type, `m/44'/1179993420'/account'/change/index`.
+///
+/// The account is hardened; the change and index aren't, so the keys of an account can be
+/// derived from its extended public key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FuelDerivationPath {
+    account: u32,
+    change: u32,
+    index: u32,
+}
+
+impl FuelDerivationPath {
+    /// BIP-44 purpose
+    pub const PURPOSE: u32 = 44;
+
+    /// SLIP-44 coin type of Fuel
+    pub const COIN_TYPE: u32 = 1179993420;
+
+    /// Change of the receive addresses
+    pub const EXTERNAL: u32 = 0;
+
+    /// Change of the change addresses
+    pub const INTERNAL: u32 = 1;
+
+    /// Path of the key at `index` of the provided account and change.
+    ///
+    /// Fails with [`Error::Bip32Error`] if any of the fields is a hardened index.
+    pub const fn new(account: u32, change: u32, index: u32) -> Result<Self, Error> {
+        if account >= BIP32_HARDEN || change >= BIP32_HARDEN || index >= BIP32_HARDEN {
+            return Err(Error::Bip32Error);
+        }
+
+        Ok(Self {
+            account,
+            change,
+            index,
+        })
+    }
+
+    /// Replace the account of the path
+    pub const fn with_account(self, account: u32) -> Result<Self, Error> {
+        Self::new(account, self.change, self.index)
+    }
+
+    /// Replace the change of the path
+    pub const fn with_change(self, change: u32) -> Result<Self, Error> {
+        Self::new(self.account, change, self.index)
+    }
+
+    /// Replace the index of the path
+    pub const fn with_index(self, index: u32) -> Result<Self, Error> {
+        Self::new(self.account, self.change, index)
+    }
+
+    /// Account of the path, without the hardened bit
+    pub const fn account(&self) -> u32 {
+        self.account
+    }
+
+    /// Change of the path
+    pub const fn change(&self) -> u32 {
+        self.change
+    }
+
+    /// Index of the path
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Path of the account node, `m/44'/1179993420'/account'`
+    pub fn account_path(&self) -> DerivationPath {
+        DerivationPath::from(vec![
+            Self::PURPOSE | BIP32_HARDEN,
+            Self::COIN_TYPE | BIP32_HARDEN,
+            self.account | BIP32_HARDEN,
+        ])
+    }
+
+    /// Secret key of the path, derived from the master key
+    pub fn derive(&self, master: &ExtendedSecretKey) -> Result<SecretKey, Error> {
+        Ok(master.derive_path(&self.into())?.secret_key())
+    }
+}
+
+impl From<&FuelDerivationPath> for DerivationPath {
+    fn from(path: &FuelDerivationPath) -> Self {
+        path.account_path()
+            .extended(path.change)
+            .extended(path.index)
+    }
+}
+
+impl From<FuelDerivationPath> for DerivationPath {
+    fn from(path: FuelDerivationPath) -> Self {
+        (&path).into()
+    }
+}
+
+impl TryFrom<&DerivationPath> for FuelDerivationPath {
+    type Error = Error;
+
+    fn try_from(path: &DerivationPath) -> Result<Self, Error> {
+        match path.iter().as_slice() {
+            [purpose, coin, account, change, index]
+                if *purpose == Self::PURPOSE | BIP32_HARDEN
+                    && *coin == Self::COIN_TYPE | BIP32_HARDEN
+                    && *account >= BIP32_HARDEN =>
+            {
+                Self::new(account & !BIP32_HARDEN, *change, *index)
+            }
+
+            _ => Err(Error::Bip32Error),
+        }
+    }
+}
+
+impl fmt::Display for FuelDerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m/{}'/{}'/{}'/{}/{}",
+            Self::PURPOSE,
+            Self::COIN_TYPE,
+            self.account,
+            self.change,
+            self.index
+        )
+    }
+}
+
+impl str::FromStr for FuelDerivationPath {
+    type Err = Error;
+
+    /// Parse a Fuel derivation path, as `m/44'/1179993420'/0'/0/0`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = DerivationPath::from_str(s)?;
+
+        Self::try_from(&path)
+    }
+}
+
+/// Account of a Fuel wallet, at the `m/44'/1179993420'/account'` node of the master key
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuelAccount {
+    account: u32,
+    key: ExtendedSecretKey,
+}
+
+impl FuelAccount {
+    /// Gap limit of the account discovery, as defined in BIP-44
+    pub const GAP_LIMIT: u32 = 20;
+
+    /// Derive the account from the master key.
+    ///
+    /// Fails with [`Error::Bip32Error`] if `account` is a hardened index.
+    pub fn new(master: &ExtendedSecretKey, account: u32) -> Result<Self, Error> {
+        let path = FuelDerivationPath::new(account, 0, 0)?;
+        let key = master.derive_path(&path.account_path())?;
+
+        Ok(Self { account, key })
+    }
+
+    /// Account number, without the hardened bit
+    pub const fn account(&self) -> u32 {
+        self.account
+    }
+
+    /// Extended secret key of the account node
+    pub const fn extended_secret_key(&self) -> &ExtendedSecretKey {
+        &self.key
+    }
+
+    /// Extended public key of the account node, which derives the public keys of the account
+    /// without its secrets
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        self.key.extended_public_key()
+    }
+
+    /// Derivation path of the key at `index` of the provided change
+    pub fn path(&self, change: u32, index: u32) -> Result<FuelDerivationPath, Error> {
+        FuelDerivationPath::new(self.account, change, index)
+    }
+
+    /// Keypair at `index` of the provided change.
+    ///
+    /// Fails with [`Error::Bip32Error`] if `change` or `index` is a hardened index.
+    pub fn derive(&self, change: u32, index: u32) -> Result<(SecretKey, PublicKey), Error> {
+        let path = self.path(change, index)?;
+        let key = self
+            .key
+            .derive_child(path.change)?
+            .derive_child(path.index)?;
+
+        Ok((key.secret_key(), key.public_key()))
+    }
+
+    /// Keypairs of the receive addresses, as `(index, secret, public)`, starting at index `0`
+    pub fn keys(&self) -> Result<AccountKeys, Error> {
+        self.chain_keys(FuelDerivationPath::EXTERNAL)
+    }
+
+    /// Keypairs of the provided change, as `(index, secret, public)`, starting at index `0`.
+    ///
+    /// Fails with [`Error::Bip32Error`] if `change` is a hardened index.
+    pub fn chain_keys(&self, change: u32) -> Result<AccountKeys, Error> {
+        self.path(change, 0)?;
+
+        Ok(AccountKeys {
+            chain: self.key.derive_child(change)?,
+            next: 0,
+        })
+    }
+
+    /// Discover the used accounts of the master key, as defined in BIP-44.
+    ///
+    /// The accounts are scanned in order. An account is used if `is_used` accepts one of its
+    /// receive addresses before `gap_limit` consecutive unused ones; the discovery stops at the
+    /// first unused account.
+    pub fn discover<F>(
+        master: &ExtendedSecretKey,
+        gap_limit: u32,
+        mut is_used: F,
+    ) -> Result<Vec<Self>, Error>
+    where
+        F: FnMut(&PublicKey) -> bool,
+    {
+        let mut accounts = Vec::new();
+
+        for account in 0..BIP32_HARDEN {
+            let account = Self::new(master, account)?;
+            let used = account
+                .keys()?
+                .take(gap_limit as usize)
+                .any(|(_, _, public)| is_used(&public));
+
+            if !used {
+                break;
+            }
+
+            accounts.push(account);
+        }
+
+        Ok(accounts)
+    }
+}
+
+/// Iterator over the keypairs of an account chain, created by [`FuelAccount::keys`] and
+/// [`FuelAccount::chain_keys`].
+///
+/// The indices that don't derive a valid key, with a probability lower than 1 in 2^127, are
+/// skipped, as defined in BIP-32.
+#[derive(Debug, Clone)]
+pub struct AccountKeys {
+    chain: ExtendedSecretKey,
+    next: u32,
+}
+
+impl Iterator for AccountKeys {
+    type Item = (u32, SecretKey, PublicKey);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < BIP32_HARDEN {
+            let key = self.chain.derive_child(self.next);
+
+            self.next += 1;
+
+            // The derivation moves to the next index if the key is invalid
+            match key {
+                Ok(key) if key.index() < BIP32_HARDEN => {
+                    self.next = key.index() + 1;
+
+                    return Some((key.index(), key.secret_key(), key.public_key()));
+                }
+
+                _ => (),
+            }
+        }
+
+        None
+    }
+}
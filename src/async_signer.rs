@@ -0,0 +1,45 @@
+use crate::{Error, Keystore, Message, PublicKey, Signature, Signer};
+
+use core::future::Future;
+
+/// Asynchronous signature provider, for signers that never expose their secret keys, as remote
+/// key management services or hardware wallets.
+///
+/// Every [`Signer`] is an asynchronous signer that resolves immediately.
+pub trait AsyncSigner {
+    /// Signer error implementation
+    type Error: From<Error>;
+
+    /// Identifier for the keypair
+    type KeyId;
+
+    /// Public key indexed by `id`
+    fn public(
+        &self,
+        id: &Self::KeyId,
+    ) -> impl Future<Output = Result<PublicKey, Self::Error>> + Send;
+
+    /// Sign a given message with the secret key identified by `id`
+    fn sign(
+        &self,
+        id: &Self::KeyId,
+        message: &Message,
+    ) -> impl Future<Output = Result<Signature, Self::Error>> + Send;
+}
+
+impl<S> AsyncSigner for S
+where
+    S: Signer + Sync,
+    <S::Keystore as Keystore>::KeyId: Sync,
+{
+    type Error = <S as Signer>::Error;
+    type KeyId = <S::Keystore as Keystore>::KeyId;
+
+    async fn public(&self, id: &Self::KeyId) -> Result<PublicKey, Self::Error> {
+        self.id_public(id).map(|public| public.into_owned())
+    }
+
+    async fn sign(&self, id: &Self::KeyId, message: &Message) -> Result<Signature, Self::Error> {
+        Signer::sign(self, id, message)
+    }
+}
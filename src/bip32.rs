@@ -0,0 +1,318 @@
+use crate::{CompressedPublicKey, Error, FuelMnemonic, PublicKey, SecretKey};
+
+use coins_bip32::enc::{MainnetEncoder, XKeyEncoder};
+use coins_bip32::path::DerivationPath;
+use coins_bip32::prelude::{Hint, Parent, SigningKey, VerifyingKey, XKeyInfo, XPriv, XPub};
+use coins_bip32::BIP32_HARDEN;
+use coins_bip39::{Mnemonic, Wordlist};
+use fuel_types::Bytes32;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+
+use core::{fmt, str};
+
+const fn is_hardened(index: u32) -> bool {
+    index >= BIP32_HARDEN
+}
+
+/// BIP-32 extended secret key: a secret key and the chain code used to derive its children.
+///
+/// Hardened children can only be derived from the extended secret key. The children of the
+/// [`ExtendedPublicKey`] are the public keys of the non-hardened children of the secret key,
+/// so the receive addresses of a wallet can be derived without its secrets.
+#[derive(Clone, PartialEq)]
+pub struct ExtendedSecretKey {
+    xpriv: XPriv,
+}
+
+impl ExtendedSecretKey {
+    // Keys are serialized with the BIP-32 `xprv` version, whatever the hint of the source
+    fn new(xpriv: XPriv) -> Self {
+        let key: &SigningKey = xpriv.as_ref();
+        let info = XKeyInfo {
+            hint: Hint::Legacy,
+            ..*xpriv.as_ref()
+        };
+
+        Self {
+            xpriv: XPriv::new(key.clone(), info),
+        }
+    }
+
+    fn info(&self) -> &XKeyInfo {
+        self.xpriv.as_ref()
+    }
+
+    /// Master key of the provided seed, as defined in BIP-32.
+    ///
+    /// Fails with [`Error::Bip32Error`] if the seed is shorter than 16 bytes, or longer than 64
+    /// bytes.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        XPriv::root_from_seed(seed, None)
+            .map(Self::new)
+            .map_err(Error::from)
+    }
+
+    /// Master key of the provided mnemonic, as defined in BIP-39, without passphrase
+    pub fn from_mnemonic<L: Wordlist>(mnemonic: &Mnemonic<L>) -> Result<Self, Error> {
+        Self::from_mnemonic_with_passphrase(mnemonic, "")
+    }
+
+    /// Master key of the provided mnemonic, protected by a BIP-39 passphrase. The passphrase is
+    /// normalized to the NFKD form.
+    pub fn from_mnemonic_with_passphrase<L: Wordlist>(
+        mnemonic: &Mnemonic<L>,
+        passphrase: &str,
+    ) -> Result<Self, Error> {
+        let passphrase: String = passphrase.nfkd().collect();
+        let mut seed = mnemonic.to_seed(Some(&passphrase))?;
+        let key = Self::from_seed(&seed);
+
+        seed.zeroize();
+
+        key
+    }
+
+    /// Master key of the provided mnemonic phrase, in any language of
+    /// [`MnemonicLanguage`](crate::MnemonicLanguage). If you want to manually create a
+    /// `Mnemonic`, use [`ExtendedSecretKey::from_mnemonic`].
+    pub fn from_mnemonic_phrase(phrase: &str) -> Result<Self, Error> {
+        Self::from_mnemonic_phrase_with_passphrase(phrase, "")
+    }
+
+    /// Master key of the provided mnemonic phrase, protected by a BIP-39 passphrase, as
+    /// in [`FuelMnemonic::to_seed`].
+    pub fn from_mnemonic_phrase_with_passphrase(
+        phrase: &str,
+        passphrase: &str,
+    ) -> Result<Self, Error> {
+        let mut seed = FuelMnemonic::to_seed(phrase, passphrase)?;
+        let key = Self::from_seed(&seed);
+
+        seed.zeroize();
+
+        key
+    }
+
+    /// Child key at `index`. A hardened index derives a hardened child.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        Ok(Self {
+            xpriv: self.xpriv.derive_child(index)?,
+        })
+    }
+
+    /// Hardened child key at `index`, as `index'` in a derivation path.
+    ///
+    /// Fails with [`Error::Bip32Error`] if `index` is already hardened.
+    pub fn derive_hardened(&self, index: u32) -> Result<Self, Error> {
+        if is_hardened(index) {
+            return Err(Error::Bip32Error);
+        }
+
+        self.derive_child(index | BIP32_HARDEN)
+    }
+
+    /// Descendant key at `path`, relative to this key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Secret key of the node
+    pub fn secret_key(&self) -> SecretKey {
+        let key: &SigningKey = self.xpriv.as_ref();
+        let mut bytes = key.to_bytes();
+
+        // Safety: the signing key is a non-zero scalar of the secp256k1 field
+        let secret = unsafe { SecretKey::from_slice_unchecked(bytes.as_ref()) };
+
+        bytes.zeroize();
+
+        secret
+    }
+
+    /// Public key of the node
+    pub fn public_key(&self) -> PublicKey {
+        self.secret_key().public_key()
+    }
+
+    /// Extended public key of the node, which derives the public keys of the non-hardened
+    /// children
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            xpub: self.xpriv.verify_key(),
+        }
+    }
+
+    /// Chain code of the node
+    pub fn chain_code(&self) -> Bytes32 {
+        self.info().chain_code.0.into()
+    }
+
+    /// Depth of the node; the master key has depth `0`
+    pub fn depth(&self) -> u8 {
+        self.info().depth
+    }
+
+    /// Child index of the node; `0` for the master key
+    pub fn index(&self) -> u32 {
+        self.info().index
+    }
+
+    /// Check if the node is a hardened child
+    pub fn is_hardened(&self) -> bool {
+        is_hardened(self.index())
+    }
+
+    /// First 4 bytes of the HASH160 of the compressed public key
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.xpriv.fingerprint().0
+    }
+
+    /// Fingerprint of the parent key; zeroed for the master key
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.info().parent.0
+    }
+
+    /// Base58Check `xprv` serialization, as defined in BIP-32
+    pub fn to_xprv(&self) -> String {
+        MainnetEncoder::xpriv_to_base58(&self.xpriv).expect("Writing to a buffer can't fail")
+    }
+}
+
+impl fmt::Debug for ExtendedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedSecretKey")
+            .field("depth", &self.depth())
+            .field("index", &self.index())
+            .field("fingerprint", &self.fingerprint())
+            .finish_non_exhaustive()
+    }
+}
+
+impl str::FromStr for ExtendedSecretKey {
+    type Err = Error;
+
+    /// Parse a Base58Check `xprv` key
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MainnetEncoder::xpriv_from_base58(s)
+            .map(Self::new)
+            .map_err(Error::from)
+    }
+}
+
+/// BIP-32 extended public key: a public key and the chain code used to derive its non-hardened
+/// children.
+#[derive(Clone, PartialEq)]
+pub struct ExtendedPublicKey {
+    xpub: XPub,
+}
+
+impl ExtendedPublicKey {
+    fn new(xpub: XPub) -> Self {
+        let key: &VerifyingKey = xpub.as_ref();
+        let info = XKeyInfo {
+            hint: Hint::Legacy,
+            ..*xpub.as_ref()
+        };
+
+        Self {
+            xpub: XPub::new(*key, info),
+        }
+    }
+
+    fn info(&self) -> &XKeyInfo {
+        self.xpub.as_ref()
+    }
+
+    /// Child key at `index`.
+    ///
+    /// Fails with [`Error::Bip32Error`] if `index` is hardened, since hardened children can only
+    /// be derived from the [`ExtendedSecretKey`].
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        Ok(Self {
+            xpub: self.xpub.derive_child(index)?,
+        })
+    }
+
+    /// Descendant key at `path`, relative to this key.
+    ///
+    /// Fails with [`Error::Bip32Error`] if the path contains a hardened index.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Public key of the node
+    pub fn public_key(&self) -> PublicKey {
+        let key: &VerifyingKey = self.xpub.as_ref();
+
+        CompressedPublicKey::try_from(key.to_sec1_bytes().as_ref())
+            .and_then(|compressed| compressed.decompress())
+            .expect("The verifying key is a valid secp256k1 point")
+    }
+
+    /// Chain code of the node
+    pub fn chain_code(&self) -> Bytes32 {
+        self.info().chain_code.0.into()
+    }
+
+    /// Depth of the node; the master key has depth `0`
+    pub fn depth(&self) -> u8 {
+        self.info().depth
+    }
+
+    /// Child index of the node; `0` for the master key
+    pub fn index(&self) -> u32 {
+        self.info().index
+    }
+
+    /// Check if the node is a hardened child
+    pub fn is_hardened(&self) -> bool {
+        is_hardened(self.index())
+    }
+
+    /// First 4 bytes of the HASH160 of the compressed public key
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.xpub.fingerprint().0
+    }
+
+    /// Fingerprint of the parent key; zeroed for the master key
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.info().parent.0
+    }
+
+    /// Base58Check `xpub` serialization, as defined in BIP-32
+    pub fn to_xpub(&self) -> String {
+        MainnetEncoder::xpub_to_base58(&self.xpub).expect("Writing to a buffer can't fail")
+    }
+}
+
+impl From<&ExtendedSecretKey> for ExtendedPublicKey {
+    fn from(key: &ExtendedSecretKey) -> Self {
+        key.extended_public_key()
+    }
+}
+
+impl fmt::Debug for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_xpub())
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_xpub())
+    }
+}
+
+impl str::FromStr for ExtendedPublicKey {
+    type Err = Error;
+
+    /// Parse a Base58Check `xpub` key
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MainnetEncoder::xpub_from_base58(s)
+            .map(Self::new)
+            .map_err(Error::from)
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{Hasher, PublicKey};
+
+use fuel_types::Bytes32;
+
+use core::fmt;
+use core::ops::Deref;
+
+// SEC1 tags for a compressed point with even and odd `y`
+const SEC1_EVEN_FLAG: u8 = 2;
+const SEC1_ODD_FLAG: u8 = 3;
+
+/// Asymmetric public key, in the SEC1 compressed form
+///
+/// Represented as `flag || x`, where the flag encodes the parity of `y`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct CompressedPublicKey([u8; CompressedPublicKey::LEN]);
+
+impl CompressedPublicKey {
+    /// Memory length of the type
+    pub const LEN: usize = 33;
+
+    /// Copy-free reference cast
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will save unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will fit the curve. The curve
+    /// security can be checked with [`CompressedPublicKey::is_in_curve`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        *Self::as_ref_unchecked(bytes)
+    }
+
+    /// Hash of the compressed public key
+    pub fn hash(&self) -> Bytes32 {
+        Hasher::hash(self.as_ref())
+    }
+}
+
+impl PublicKey {
+    /// Compress the public key to its SEC1 form
+    ///
+    /// The compression doesn't check the curve, so it will produce an invalid compressed key
+    /// if the public key isn't in the curve.
+    pub fn compress(&self) -> CompressedPublicKey {
+        let mut compressed = [SEC1_EVEN_FLAG; CompressedPublicKey::LEN];
+
+        if self[Self::LEN - 1] & 1 == 1 {
+            compressed[0] = SEC1_ODD_FLAG;
+        }
+
+        compressed[1..].copy_from_slice(&self[..32]);
+
+        CompressedPublicKey(compressed)
+    }
+}
+
+impl From<&PublicKey> for CompressedPublicKey {
+    fn from(pk: &PublicKey) -> Self {
+        pk.compress()
+    }
+}
+
+impl Deref for CompressedPublicKey {
+    type Target = [u8; CompressedPublicKey::LEN];
+
+    fn deref(&self) -> &[u8; CompressedPublicKey::LEN] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for CompressedPublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for CompressedPublicKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<CompressedPublicKey> for [u8; CompressedPublicKey::LEN] {
+    fn from(pk: CompressedPublicKey) -> [u8; CompressedPublicKey::LEN] {
+        pk.0
+    }
+}
+
+impl fmt::LowerHex for CompressedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::UpperHex for CompressedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+
+        self.0.iter().try_for_each(|b| write!(f, "{:02X}", b))
+    }
+}
+
+impl fmt::Debug for CompressedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+impl fmt::Display for CompressedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod use_serde {
+    use super::*;
+
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    // `serde` implements arrays up to 32 elements, so the key is serialized as a tuple of bytes.
+    // The curve is not checked, consistent with the derived implementation of `PublicKey`.
+    impl Serialize for CompressedPublicKey {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut tuple = serializer.serialize_tuple(Self::LEN)?;
+
+            self.0.iter().try_for_each(|b| tuple.serialize_element(b))?;
+
+            tuple.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CompressedPublicKey {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct CompressedVisitor;
+
+            impl<'de> Visitor<'de> for CompressedVisitor {
+                type Value = CompressedPublicKey;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{} bytes", CompressedPublicKey::LEN)
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut pk = [0u8; CompressedPublicKey::LEN];
+
+                    for (i, b) in pk.iter_mut().enumerate() {
+                        *b = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                    }
+
+                    Ok(CompressedPublicKey(pk))
+                }
+            }
+
+            deserializer.deserialize_tuple(Self::LEN, CompressedVisitor)
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
+    use super::*;
+    use crate::util::decode_hex;
+    use crate::Error;
+
+    use core::str;
+
+    impl CompressedPublicKey {
+        /// Check if the compressed public key representation is in the curve.
+        pub fn is_in_curve(&self) -> bool {
+            // Safety: struct is guaranteed to reference itself with correct len
+            unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
+        }
+    }
+
+    impl TryFrom<[u8; CompressedPublicKey::LEN]> for CompressedPublicKey {
+        type Error = Error;
+
+        fn try_from(b: [u8; CompressedPublicKey::LEN]) -> Result<Self, Self::Error> {
+            let public = CompressedPublicKey(b);
+
+            public
+                .is_in_curve()
+                .then_some(public)
+                .ok_or(Error::InvalidPublicKey)
+        }
+    }
+
+    impl TryFrom<&[u8]> for CompressedPublicKey {
+        type Error = Error;
+
+        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+            <[u8; CompressedPublicKey::LEN]>::try_from(slice)
+                .map_err(|_| Error::InvalidPublicKey)
+                .and_then(CompressedPublicKey::try_from)
+        }
+    }
+
+    impl TryFrom<&CompressedPublicKey> for PublicKey {
+        type Error = Error;
+
+        fn try_from(pk: &CompressedPublicKey) -> Result<Self, Self::Error> {
+            pk.decompress()
+        }
+    }
+
+    impl str::FromStr for CompressedPublicKey {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+
+            let mut pk = [0u8; Self::LEN];
+
+            decode_hex(s, &mut pk).ok_or(Error::InvalidPublicKey)?;
+
+            CompressedPublicKey::try_from(pk)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod use_std {
+    use super::*;
+    use crate::Error;
+
+    use secp256k1::PublicKey as Secp256k1PublicKey;
+
+    impl CompressedPublicKey {
+        /// Check if the provided slice represents a compressed public key that is in the
+        /// curve.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`CompressedPublicKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+            let public = Self::as_ref_unchecked(slice);
+
+            Secp256k1PublicKey::from_slice(public.as_ref()).is_ok()
+        }
+
+        /// Decompress the public key, recovering its `y` coordinate
+        pub fn decompress(&self) -> Result<PublicKey, Error> {
+            let pk = Secp256k1PublicKey::from_slice(self.as_ref())?;
+
+            Ok(PublicKey::from_secp(&pk))
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use super::*;
+    use crate::Error;
+
+    use k256::ecdsa::VerifyingKey;
+
+    impl CompressedPublicKey {
+        /// Check if the provided slice represents a compressed public key that is in the
+        /// curve.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`CompressedPublicKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+            Self::as_ref_unchecked(slice).decompress().is_ok()
+        }
+
+        /// Decompress the public key, recovering its `y` coordinate
+        pub fn decompress(&self) -> Result<PublicKey, Error> {
+            // `from_sec1_bytes` also accepts uncompressed points, so the flag is checked here
+            if self[0] != SEC1_EVEN_FLAG && self[0] != SEC1_ODD_FLAG {
+                return Err(Error::InvalidPublicKey);
+            }
+
+            VerifyingKey::from_sec1_bytes(self.as_ref())
+                .map(|pk| PublicKey::from_k256(&pk))
+                .map_err(|_| Error::InvalidPublicKey)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Ed25519 primitives, as defined in RFC 8032.
+//!
+//! The types follow the conventions of their secp256k1 counterparts exported from the crate
+//! root.
+
+mod public;
+mod secret;
+mod signature;
+
+pub use public::PublicKey;
+pub use secret::SecretKey;
+pub use signature::Signature;
@@ -0,0 +1,177 @@
+use crate::ed25519::SecretKey;
+use crate::{Error, Hasher};
+
+use ed25519_dalek::VerifyingKey;
+use fuel_types::Bytes32;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+/// Ed25519 public key
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct PublicKey(Bytes32);
+
+impl PublicKey {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes32::LEN;
+
+    /// Copy-free reference cast
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will save unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will fit the curve. The curve
+    /// security can be checked with [`PublicKey::is_in_curve`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes32::from_slice_unchecked(bytes))
+    }
+
+    /// Check if the provided slice represents a public key that is in the
+    /// curve.
+    ///
+    /// # Safety
+    ///
+    /// This function extends the unsafety of
+    /// [`PublicKey::as_ref_unchecked`].
+    pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+        Self::as_ref_unchecked(slice).to_dalek().is_ok()
+    }
+
+    /// Check if the public key representation is in the curve.
+    pub fn is_in_curve(&self) -> bool {
+        // Safety: struct is guaranteed to reference itself with correct len
+        unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
+    }
+
+    /// Hash of the public key
+    pub fn hash(&self) -> Bytes32 {
+        Hasher::hash(self.as_ref())
+    }
+
+    pub(crate) fn to_dalek(self) -> Result<VerifyingKey, Error> {
+        VerifyingKey::from_bytes(&self.0).map_err(|_| Error::InvalidPublicKey)
+    }
+}
+
+impl Deref for PublicKey {
+    type Target = [u8; PublicKey::LEN];
+
+    fn deref(&self) -> &[u8; PublicKey::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for PublicKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<PublicKey> for [u8; PublicKey::LEN] {
+    fn from(pk: PublicKey) -> [u8; PublicKey::LEN] {
+        pk.0.into()
+    }
+}
+
+impl From<&SecretKey> for PublicKey {
+    fn from(s: &SecretKey) -> PublicKey {
+        let public = s.to_dalek().verifying_key();
+
+        PublicKey(public.to_bytes().into())
+    }
+}
+
+impl TryFrom<Bytes32> for PublicKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+        let public = PublicKey(b);
+
+        public
+            .is_in_curve()
+            .then_some(public)
+            .ok_or(Error::InvalidPublicKey)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl str::FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl fmt::LowerHex for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
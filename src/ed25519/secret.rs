@@ -0,0 +1,156 @@
+use crate::ed25519::PublicKey;
+use crate::Error;
+
+use ed25519_dalek::SigningKey;
+use fuel_types::Bytes32;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+use zeroize::Zeroize;
+
+/// Ed25519 secret key
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SecretKey(Bytes32);
+
+impl SecretKey {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes32::LEN;
+
+    /// Create a new random secret
+    #[cfg(feature = "random")]
+    pub fn random<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        // Every 32-byte seed is a valid Ed25519 secret key
+        let mut secret = Bytes32::zeroed();
+
+        rng.fill(secret.as_mut());
+
+        Self(secret)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// Every 32-byte seed is a valid Ed25519 secret key. This function is unsafe only for
+    /// consistency with [`crate::SecretKey::from_bytes_unchecked`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes32::from_slice_unchecked(bytes))
+    }
+
+    /// Copy-free reference cast
+    ///
+    /// # Safety
+    ///
+    /// Inputs smaller than `Self::LEN` will cause undefined behavior.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will avoid unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Return the curve representation of this secret.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+
+    pub(crate) fn to_dalek(self) -> SigningKey {
+        SigningKey::from_bytes(&self.0)
+    }
+}
+
+impl Deref for SecretKey {
+    type Target = [u8; SecretKey::LEN];
+
+    fn deref(&self) -> &[u8; SecretKey::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for SecretKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<SecretKey> for [u8; SecretKey::LEN] {
+    fn from(secret: SecretKey) -> [u8; SecretKey::LEN] {
+        secret.0.into()
+    }
+}
+
+impl From<Bytes32> for SecretKey {
+    fn from(b: Bytes32) -> Self {
+        Self(b)
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidSecretKey)
+            .map(SecretKey)
+    }
+}
+
+impl str::FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidSecretKey)
+            .map(SecretKey)
+    }
+}
+
+impl fmt::LowerHex for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "random")]
+impl rand::distributions::Distribution<SecretKey> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SecretKey {
+        SecretKey::random(rng)
+    }
+}
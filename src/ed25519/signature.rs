@@ -0,0 +1,157 @@
+use crate::ed25519::{PublicKey, SecretKey};
+use crate::Error;
+
+use ed25519_dalek::Signer;
+use fuel_types::Bytes64;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+/// Ed25519 signature
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Signature(Bytes64);
+
+impl Signature {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes64::LEN;
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes64::from_slice_unchecked(bytes))
+    }
+
+    /// Copy-free reference cast
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    ///
+    /// # Safety
+    ///
+    /// Inputs smaller than `Self::LEN` will cause undefined behavior.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will avoid unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Sign a given message
+    ///
+    /// Ed25519 hashes the message internally, so it can be either a [`crate::Message`] or
+    /// arbitrary bytes.
+    pub fn sign<M>(secret: &SecretKey, message: M) -> Self
+    where
+        M: AsRef<[u8]>,
+    {
+        let signature = secret.to_dalek().sign(message.as_ref());
+
+        Self(signature.to_bytes().into())
+    }
+
+    /// Verify a signature produced by [`Signature::sign`]
+    ///
+    /// The verification is strict; non-canonical signatures and weak public keys are
+    /// rejected.
+    pub fn verify<M>(&self, pk: &PublicKey, message: M) -> Result<(), Error>
+    where
+        M: AsRef<[u8]>,
+    {
+        let signature = ed25519_dalek::Signature::from_bytes(&self.0);
+        let pk = pk.to_dalek()?;
+
+        pk.verify_strict(message.as_ref(), &signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+impl Deref for Signature {
+    type Target = [u8; Signature::LEN];
+
+    fn deref(&self) -> &[u8; Signature::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for Signature {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<Signature> for [u8; Signature::LEN] {
+    fn from(signature: Signature) -> [u8; Signature::LEN] {
+        signature.0.into()
+    }
+}
+
+impl From<Bytes64> for Signature {
+    fn from(b: Bytes64) -> Self {
+        Self(b)
+    }
+}
+
+impl From<Signature> for Bytes64 {
+    fn from(s: Signature) -> Self {
+        s.0
+    }
+}
+
+impl str::FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| s.into())
+    }
+}
+
+impl fmt::LowerHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
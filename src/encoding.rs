@@ -0,0 +1,204 @@
+//! SEC1, PKCS#8 and SubjectPublicKeyInfo encodings of the secp256k1 keys.
+//!
+//! The encodings are the same produced by OpenSSL for `secp256k1` keys.
+
+use crate::{Error, PublicKey, SecretKey};
+
+use alloc::vec::Vec;
+use pkcs8::der::asn1::{AnyRef, BitStringRef, ObjectIdentifier};
+use pkcs8::der::{Decode, Encode};
+use pkcs8::spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
+use pkcs8::PrivateKeyInfo;
+use sec1::{EcParameters, EcPrivateKey};
+use zeroize::Zeroizing;
+
+/// `id-ecPublicKey`, as defined in RFC 5480
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `secp256k1`, as defined in SEC 2
+const SECP256K1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+
+const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+
+// SEC1 tag for an uncompressed point
+const SEC1_UNCOMPRESSED_FLAG: u8 = 4;
+
+fn algorithm() -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifierRef {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some(AnyRef::from(&SECP256K1_OID)),
+    }
+}
+
+fn check_algorithm(algorithm: &AlgorithmIdentifierRef) -> Result<(), Error> {
+    match algorithm.oids() {
+        Ok((EC_PUBLIC_KEY_OID, Some(SECP256K1_OID))) => Ok(()),
+        Ok(_) => Err(Error::UnsupportedCurve),
+        Err(_) => Err(Error::InvalidDer),
+    }
+}
+
+fn sec1_uncompressed(pk: &PublicKey) -> [u8; UNCOMPRESSED_PUBLIC_KEY_SIZE] {
+    let mut sec1 = [SEC1_UNCOMPRESSED_FLAG; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+
+    sec1[1..].copy_from_slice(pk.as_ref());
+
+    sec1
+}
+
+impl SecretKey {
+    fn from_ec_private_key(key: EcPrivateKey) -> Result<Self, Error> {
+        // The parameters are optional, but they must match the curve if provided
+        match key.parameters.map(EcParameters::named_curve) {
+            None | Some(Some(SECP256K1_OID)) => (),
+            Some(_) => return Err(Error::UnsupportedCurve),
+        }
+
+        let secret = SecretKey::try_from(key.private_key)?;
+
+        if let Some(public) = key.public_key {
+            if PublicKey::from_sec1_bytes(public)? != secret.public_key() {
+                return Err(Error::InvalidPublicKey);
+            }
+        }
+
+        Ok(secret)
+    }
+
+    fn to_ec_private_key(&self, parameters: Option<EcParameters>) -> Zeroizing<Vec<u8>> {
+        let public = sec1_uncompressed(&self.public_key());
+
+        let key = EcPrivateKey {
+            private_key: self.as_ref(),
+            parameters,
+            public_key: Some(&public),
+        };
+
+        key.to_der()
+            .map(Zeroizing::new)
+            .expect("A secp256k1 secret key is always encodable")
+    }
+
+    /// Decode a secret key from a SEC1 `ECPrivateKey` document, as defined in RFC 5915.
+    ///
+    /// If the document contains the public key, it must match the secret key.
+    pub fn from_sec1_der(bytes: &[u8]) -> Result<Self, Error> {
+        EcPrivateKey::from_der(bytes)
+            .map_err(|_| Error::InvalidDer)
+            .and_then(Self::from_ec_private_key)
+    }
+
+    /// Encode the secret key to a SEC1 `ECPrivateKey` document, as defined in RFC 5915.
+    pub fn to_sec1_der(&self) -> Zeroizing<Vec<u8>> {
+        self.to_ec_private_key(Some(EcParameters::NamedCurve(SECP256K1_OID)))
+    }
+
+    /// Decode a secret key from a PKCS#8 `PrivateKeyInfo` document, as defined in RFC 5208.
+    pub fn from_pkcs8_der(bytes: &[u8]) -> Result<Self, Error> {
+        let info = PrivateKeyInfo::from_der(bytes).map_err(|_| Error::InvalidDer)?;
+
+        check_algorithm(&info.algorithm)?;
+
+        EcPrivateKey::from_der(info.private_key)
+            .map_err(|_| Error::InvalidDer)
+            .and_then(Self::from_ec_private_key)
+    }
+
+    /// Encode the secret key to a PKCS#8 `PrivateKeyInfo` document, as defined in RFC 5208.
+    pub fn to_pkcs8_der(&self) -> Zeroizing<Vec<u8>> {
+        // The curve is already described by the algorithm identifier
+        let key = self.to_ec_private_key(None);
+
+        PrivateKeyInfo::new(algorithm(), &key)
+            .to_der()
+            .map(Zeroizing::new)
+            .expect("A secp256k1 secret key is always encodable")
+    }
+}
+
+impl PublicKey {
+    /// Decode a public key from a X.509 `SubjectPublicKeyInfo` document, as defined in RFC 5280.
+    pub fn from_public_key_der(bytes: &[u8]) -> Result<Self, Error> {
+        let info = SubjectPublicKeyInfoRef::from_der(bytes).map_err(|_| Error::InvalidDer)?;
+
+        check_algorithm(&info.algorithm)?;
+
+        info.subject_public_key
+            .as_bytes()
+            .ok_or(Error::InvalidDer)
+            .and_then(PublicKey::from_sec1_bytes)
+    }
+
+    /// Encode the public key to a X.509 `SubjectPublicKeyInfo` document, as defined in RFC 5280.
+    ///
+    /// The point is encoded in its uncompressed form.
+    pub fn to_public_key_der(&self) -> Vec<u8> {
+        let public = sec1_uncompressed(self);
+
+        let info = SubjectPublicKeyInfoRef {
+            algorithm: algorithm(),
+            subject_public_key: BitStringRef::from_bytes(&public)
+                .expect("A byte slice is always a valid bit string"),
+        };
+
+        info.to_der()
+            .expect("A secp256k1 public key is always encodable")
+    }
+}
+
+#[cfg(feature = "pem")]
+mod use_pem {
+    use super::*;
+
+    use alloc::string::String;
+    use pkcs8::der::pem::{self, LineEnding};
+
+    const EC_PRIVATE_KEY_LABEL: &str = "EC PRIVATE KEY";
+    const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+    const PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+
+    fn decode(s: &str, label: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let (l, der) = pem::decode_vec(s.as_bytes()).map_err(|_| Error::InvalidPem)?;
+        let der = Zeroizing::new(der);
+
+        (l == label).then_some(der).ok_or(Error::InvalidPem)
+    }
+
+    fn encode(der: &[u8], label: &str) -> String {
+        pem::encode_string(label, LineEnding::LF, der).expect("A DER document is always encodable")
+    }
+
+    impl SecretKey {
+        /// Decode a secret key from a SEC1 `EC PRIVATE KEY` PEM document.
+        pub fn from_sec1_pem(s: &str) -> Result<Self, Error> {
+            decode(s, EC_PRIVATE_KEY_LABEL).and_then(|der| Self::from_sec1_der(&der))
+        }
+
+        /// Encode the secret key to a SEC1 `EC PRIVATE KEY` PEM document.
+        pub fn to_sec1_pem(&self) -> Zeroizing<String> {
+            Zeroizing::new(encode(&self.to_sec1_der(), EC_PRIVATE_KEY_LABEL))
+        }
+
+        /// Decode a secret key from a PKCS#8 `PRIVATE KEY` PEM document.
+        pub fn from_pkcs8_pem(s: &str) -> Result<Self, Error> {
+            decode(s, PRIVATE_KEY_LABEL).and_then(|der| Self::from_pkcs8_der(&der))
+        }
+
+        /// Encode the secret key to a PKCS#8 `PRIVATE KEY` PEM document.
+        pub fn to_pkcs8_pem(&self) -> Zeroizing<String> {
+            Zeroizing::new(encode(&self.to_pkcs8_der(), PRIVATE_KEY_LABEL))
+        }
+    }
+
+    impl PublicKey {
+        /// Decode a public key from a `PUBLIC KEY` PEM document.
+        pub fn from_public_key_pem(s: &str) -> Result<Self, Error> {
+            decode(s, PUBLIC_KEY_LABEL).and_then(|der| Self::from_public_key_der(&der))
+        }
+
+        /// Encode the public key to a `PUBLIC KEY` PEM document.
+        pub fn to_public_key_pem(&self) -> String {
+            encode(&self.to_public_key_der(), PUBLIC_KEY_LABEL)
+        }
+    }
+}
@@ -30,6 +30,62 @@ pub enum Error {
 
     /// Bip32-related error
     Bip32Error,
+
+    /// Invalid WebAuthn authenticator data
+    InvalidAuthenticatorData,
+
+    /// Invalid WebAuthn client data
+    InvalidClientData,
+
+    /// Invalid ASN.1 DER document
+    InvalidDer,
+
+    /// Invalid PEM document, or unexpected PEM label
+    InvalidPem,
+
+    /// The encoded key doesn't belong to the expected curve
+    UnsupportedCurve,
+
+    /// The signature is not in the canonical lower-S form, or its components are out of the
+    /// field
+    NonCanonicalSignature,
+
+    /// Invalid Ethereum address, or mismatching EIP-55 checksum
+    InvalidAddress,
+
+    /// The EIP-155 chain id overflows the `v` value of the signature
+    InvalidChainId,
+
+    /// Invalid EIP-712 typed data, or a value that doesn't match its type
+    InvalidTypedData,
+
+    /// Malformed Merkle inclusion proof
+    InvalidMerkleProof,
+
+    /// The storage of the Merkle tree is missing a node, or is corrupted
+    InvalidMerkleTree,
+
+    /// The requested output exceeds the maximum length of the key derivation function
+    InvalidOutputLength,
+
+    /// Malformed or unsupported encrypted key document
+    InvalidEncryptedKey,
+
+    /// The password doesn't match the MAC of the encrypted key
+    InvalidPassword,
+
+    /// A key with the same label or public key is already stored
+    KeyAlreadyExists,
+
+    /// The label can't identify a stored key
+    InvalidKeyLabel,
+
+    /// The word at the provided position of the mnemonic phrase, starting at `0`, isn't in the
+    /// wordlist
+    InvalidMnemonicWord(usize),
+
+    /// The words of the mnemonic phrase don't match its checksum
+    InvalidMnemonicChecksum,
 }
 
 impl From<Error> for Infallible {
@@ -95,7 +151,7 @@ mod use_std {
 
     impl From<Error> for io::Error {
         fn from(e: Error) -> io::Error {
-            io::Error::new(io::ErrorKind::Other, e)
+            io::Error::other(e)
         }
     }
 }
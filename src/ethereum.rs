@@ -0,0 +1,344 @@
+//! Ethereum interoperability of the secp256k1 primitives.
+//!
+//! Includes the conversion of [`Signature`] to and from the 65-bytes `r || s || v` format, the
+//! derivation of an [`Address`] from a [`PublicKey`] and the EIP-191 `personal_sign` [`Message`].
+//! EIP-712 typed structured data is hashed with the [`eip712`] module.
+
+use crate::util::decode_hex;
+use crate::{Error, Keccak256Hasher, Message, PublicKey, Signature};
+
+use fuel_types::Bytes32;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+#[cfg(feature = "eip712")]
+pub mod eip712;
+
+/// Prefix of the EIP-191 `personal_sign` messages, as defined in
+/// <https://eips.ethereum.org/EIPS/eip-191>
+const PERSONAL_SIGN_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Offset of the legacy `v` values, `27` and `28`
+const V_LEGACY_OFFSET: u64 = 27;
+
+/// Offset of the EIP-155 `v` values, `chain_id * 2 + 35` and `chain_id * 2 + 36`
+const V_EIP155_OFFSET: u64 = 35;
+
+/// Keccak-256 digest of the provided data, as used by Ethereum
+pub fn keccak256<B>(data: B) -> Bytes32
+where
+    B: AsRef<[u8]>,
+{
+    Keccak256Hasher::hash(data)
+}
+
+/// Ethereum address: the last 20 bytes of the Keccak-256 digest of a public key
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Address([u8; Address::LEN]);
+
+impl Address {
+    /// Memory length of the type
+    pub const LEN: usize = 20;
+
+    /// Create a new address from its bytes
+    pub const fn new(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derive the address of a public key
+    pub fn from_public_key(pk: &PublicKey) -> Self {
+        let mut address = [0u8; Self::LEN];
+
+        address.copy_from_slice(&keccak256(pk)[Bytes32::LEN - Self::LEN..]);
+
+        Self(address)
+    }
+
+    // Uppercase mask of the EIP-55 checksum: a hex digit is uppercase if the matching nibble of
+    // the digest of the lowercase hex address is at least 8
+    fn checksum(&self) -> [bool; Self::LEN * 2] {
+        let mut hex = [0u8; Self::LEN * 2];
+
+        for (h, b) in hex.chunks_exact_mut(2).zip(self.0.iter()) {
+            h[0] = HEX_LOWER[(b >> 4) as usize];
+            h[1] = HEX_LOWER[(b & 0x0f) as usize];
+        }
+
+        let digest = keccak256(hex);
+        let mut mask = [false; Self::LEN * 2];
+
+        for (i, m) in mask.iter_mut().enumerate() {
+            let nibble = (digest[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+
+            *m = nibble >= 8;
+        }
+
+        mask
+    }
+}
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+impl From<&PublicKey> for Address {
+    fn from(pk: &PublicKey) -> Self {
+        Self::from_public_key(pk)
+    }
+}
+
+impl From<[u8; Address::LEN]> for Address {
+    fn from(bytes: [u8; Address::LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Address> for [u8; Address::LEN] {
+    fn from(address: Address) -> [u8; Address::LEN] {
+        address.0
+    }
+}
+
+impl TryFrom<&[u8]> for Address {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; Address::LEN]>::try_from(slice)
+            .map(Self)
+            .map_err(|_| Error::InvalidAddress)
+    }
+}
+
+impl Deref for Address {
+    type Target = [u8; Address::LEN];
+
+    fn deref(&self) -> &[u8; Address::LEN] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for Address {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl fmt::LowerHex for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::UpperHex for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+
+        self.0.iter().try_for_each(|b| write!(f, "{:02X}", b))
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::LowerHex>::fmt(self, f)
+    }
+}
+
+/// The address is displayed with the `0x` prefix and the EIP-55 mixed-case checksum, as defined in
+/// <https://eips.ethereum.org/EIPS/eip-55>
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let checksum = self.checksum();
+
+        write!(f, "0x")?;
+
+        self.0
+            .iter()
+            .flat_map(|b| [b >> 4, b & 0x0f])
+            .zip(checksum.iter())
+            .try_for_each(|(n, upper)| {
+                let hex = if *upper { HEX_UPPER } else { HEX_LOWER };
+
+                write!(f, "{}", hex[n as usize] as char)
+            })
+    }
+}
+
+/// Parse an hex address, with an optional `0x` prefix.
+///
+/// Mixed-case addresses must match their EIP-55 checksum, while lowercase and uppercase addresses
+/// are accepted without a checksum.
+impl str::FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+
+        let mut address = [0u8; Self::LEN];
+
+        decode_hex(s, &mut address).ok_or(Error::InvalidAddress)?;
+
+        let address = Self(address);
+
+        let lower = s.iter().any(u8::is_ascii_lowercase);
+        let upper = s.iter().any(u8::is_ascii_uppercase);
+
+        if lower && upper {
+            let checksum = address.checksum();
+            let valid = s
+                .iter()
+                .zip(checksum.iter())
+                .all(|(c, u)| !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == *u);
+
+            if !valid {
+                return Err(Error::InvalidAddress);
+            }
+        }
+
+        Ok(address)
+    }
+}
+
+impl PublicKey {
+    /// Ethereum address of the public key
+    pub fn eth_address(&self) -> Address {
+        Address::from_public_key(self)
+    }
+}
+
+impl Message {
+    /// Create an EIP-191 `personal_sign` message:
+    /// `keccak256("\x19Ethereum Signed Message:\n" || len(data) || data)`, where the length is
+    /// encoded as a decimal string.
+    pub fn new_personal_sign<M>(data: M) -> Self
+    where
+        M: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+
+        // `usize` has at most 20 decimal digits
+        let mut len = [0u8; 20];
+        let mut i = len.len();
+        let mut n = data.len();
+
+        loop {
+            i -= 1;
+            len[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        Keccak256Hasher::default()
+            .chain(PERSONAL_SIGN_PREFIX)
+            .chain(&len[i..])
+            .chain(data)
+            .into()
+    }
+}
+
+impl Signature {
+    /// Memory length of the Ethereum `r || s || v` representation
+    pub const ETH_LEN: usize = 65;
+
+    /// Parity of the `y` coordinate of the nonce point, as encoded in the recovery id
+    fn y_parity(&self) -> u64 {
+        (self[32] >> 7) as u64
+    }
+
+    /// Ethereum `v` value of the signature.
+    ///
+    /// Without a chain id, the legacy `27 + y_parity` value is returned. Otherwise, the EIP-155
+    /// `chain_id * 2 + 35 + y_parity` value is returned, as defined in
+    /// <https://eips.ethereum.org/EIPS/eip-155>
+    ///
+    /// Fails with [`Error::InvalidChainId`] if the EIP-155 value overflows a `u64`, which can
+    /// only happen for chain ids above `(u64::MAX - 36) / 2`.
+    pub fn eth_v(&self, chain_id: Option<u64>) -> Result<u64, Error> {
+        match chain_id {
+            Some(chain_id) => chain_id
+                .checked_mul(2)
+                .and_then(|v| v.checked_add(V_EIP155_OFFSET + self.y_parity()))
+                .ok_or(Error::InvalidChainId),
+
+            None => Ok(V_LEGACY_OFFSET + self.y_parity()),
+        }
+    }
+
+    /// Convert the signature to the Ethereum `r || s || v` format, with the legacy `v` values
+    /// `27` and `28`.
+    pub fn to_eth_bytes(&self) -> [u8; Self::ETH_LEN] {
+        let mut bytes = [0u8; Self::ETH_LEN];
+
+        bytes[..Self::LEN].copy_from_slice(self.as_ref());
+        bytes[32] &= 0x7f;
+        bytes[Self::LEN] = (V_LEGACY_OFFSET + self.y_parity()) as u8;
+
+        bytes
+    }
+
+    /// Create a signature from its Ethereum `r`, `s` and `v` components.
+    ///
+    /// The `v` value can be the raw `y` parity (`0` or `1`), the legacy `27` or `28`, or an
+    /// EIP-155 value `chain_id * 2 + 35 + y_parity`, for any chain id.
+    ///
+    /// The most significant bit of `s` is taken by the recovery id, so signatures with `s`
+    /// in the upper half of the field are rejected with [`Error::NonCanonicalSignature`]. The
+    /// range isn't fully checked; use [`Signature::recover_strict`] for consensus-critical
+    /// validation.
+    pub fn from_eth_parts(r: &Bytes32, s: &Bytes32, v: u64) -> Result<Self, Error> {
+        let y_parity = match v {
+            0 | 1 => v,
+            27 | 28 => v - V_LEGACY_OFFSET,
+            v if v >= V_EIP155_OFFSET => (v - V_EIP155_OFFSET) % 2,
+            _ => return Err(Error::InvalidSignature),
+        };
+
+        if s[0] & 0x80 != 0 {
+            return Err(Error::NonCanonicalSignature);
+        }
+
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[..32].copy_from_slice(r.as_ref());
+        bytes[32..].copy_from_slice(s.as_ref());
+        bytes[32] |= (y_parity as u8) << 7;
+
+        // Safety: the signature is validated by the recovery
+        Ok(unsafe { Signature::from_bytes_unchecked(bytes) })
+    }
+
+    /// Create a signature from the Ethereum `r || s || v` format, where `v` is a single byte.
+    ///
+    /// Check [`Signature::from_eth_parts`] for the accepted `v` values.
+    pub fn from_eth_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::ETH_LEN {
+            return Err(Error::InvalidSignature);
+        }
+
+        // Safety: the length is checked above
+        let (r, s) = unsafe {
+            (
+                Bytes32::from_slice_unchecked(&bytes[..32]),
+                Bytes32::from_slice_unchecked(&bytes[32..64]),
+            )
+        };
+
+        Self::from_eth_parts(&r, &s, bytes[Self::LEN] as u64)
+    }
+}
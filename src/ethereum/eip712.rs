@@ -0,0 +1,500 @@
+//! EIP-712 hashing of typed structured data.
+//!
+//! The typed data is described by its struct types, the primary type and the values of the domain
+//! and the message, as defined in <https://eips.ethereum.org/EIPS/eip-712>. It can be parsed from
+//! the JSON payload of `eth_signTypedData_v4`, or built with [`TypedData::new`] and
+//! [`TypedData::with_type`] from [`serde_json::Value`]s.
+//!
+//! The produced [`Message`] is `keccak256("\x19\x01" || domainSeparator || hashStruct(message))`,
+//! so it can be signed and recovered with [`crate::Signature`] as an Ethereum wallet would.
+
+use super::{keccak256, Address};
+use crate::util::decode_hex;
+use crate::{Error, Keccak256Hasher, Message};
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use fuel_types::Bytes32;
+use serde_json::Value;
+
+use core::str::FromStr;
+
+/// Name of the domain struct type
+pub const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Fields of the domain struct, in the order used when the type isn't explicitly provided
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// Member of a struct type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Field {
+    /// Name of the member
+    pub name: String,
+    /// Type of the member: an atomic, dynamic or struct type, or an array of them
+    pub ty: String,
+}
+
+impl Field {
+    /// Create a new struct member
+    pub fn new<N, T>(name: N, ty: T) -> Self
+    where
+        N: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            ty: ty.into(),
+        }
+    }
+}
+
+/// EIP-712 typed structured data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedData {
+    types: BTreeMap<String, Vec<Field>>,
+    primary_type: String,
+    domain: Value,
+    message: Value,
+}
+
+impl TypedData {
+    /// Create typed data for the given primary type, domain and message values.
+    ///
+    /// The struct types are added with [`TypedData::with_type`]. If the [`DOMAIN_TYPE`] isn't
+    /// added, it is inferred from the members present in the domain value.
+    pub fn new<P>(primary_type: P, domain: Value, message: Value) -> Self
+    where
+        P: Into<String>,
+    {
+        Self {
+            types: BTreeMap::new(),
+            primary_type: primary_type.into(),
+            domain,
+            message,
+        }
+    }
+
+    /// Add a struct type, described by the `(name, type)` of its members
+    pub fn with_type<N, I, F, T>(mut self, name: N, fields: I) -> Self
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = (F, T)>,
+        F: Into<String>,
+        T: Into<String>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|(name, ty)| Field::new(name, ty))
+            .collect();
+
+        self.types.insert(name.into(), fields);
+        self
+    }
+
+    /// Parse the JSON payload of `eth_signTypedData_v4`, with the members `types`,
+    /// `primaryType`, `domain` and `message`.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(json).map_err(|_| Error::InvalidTypedData)?;
+        let value = value.as_object().ok_or(Error::InvalidTypedData)?;
+
+        let primary_type = value
+            .get("primaryType")
+            .and_then(Value::as_str)
+            .ok_or(Error::InvalidTypedData)?;
+
+        let domain = value.get("domain").ok_or(Error::InvalidTypedData)?;
+        let message = value.get("message").ok_or(Error::InvalidTypedData)?;
+
+        let types = value
+            .get("types")
+            .and_then(Value::as_object)
+            .ok_or(Error::InvalidTypedData)?;
+
+        types.iter().try_fold(
+            Self::new(primary_type, domain.clone(), message.clone()),
+            |data, (name, fields)| {
+                let fields = fields
+                    .as_array()
+                    .ok_or(Error::InvalidTypedData)?
+                    .iter()
+                    .map(|field| {
+                        let name = field.get("name").and_then(Value::as_str);
+                        let ty = field.get("type").and_then(Value::as_str);
+
+                        name.zip(ty).ok_or(Error::InvalidTypedData)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(data.with_type(name.as_str(), fields))
+            },
+        )
+    }
+
+    /// Primary type of the message
+    pub fn primary_type(&self) -> &str {
+        &self.primary_type
+    }
+
+    /// Encode a struct type with its referenced struct types, as `encodeType` of the
+    /// specification: `Mail(Person from,Person to,string contents)Person(string name,address wallet)`
+    pub fn encode_type(&self, name: &str) -> Result<String, Error> {
+        let fields = self.struct_fields(name)?;
+
+        let mut dependencies = BTreeSet::new();
+
+        self.dependencies(name, &mut dependencies);
+        dependencies.remove(name);
+
+        let mut encoded = String::new();
+
+        Self::encode_struct_type(&mut encoded, name, &fields);
+
+        for dependency in dependencies {
+            Self::encode_struct_type(&mut encoded, dependency, &self.types[dependency]);
+        }
+
+        Ok(encoded)
+    }
+
+    /// Keccak-256 digest of [`TypedData::encode_type`]
+    pub fn type_hash(&self, name: &str) -> Result<Bytes32, Error> {
+        self.encode_type(name).map(keccak256)
+    }
+
+    /// Hash a struct value, as `hashStruct` of the specification.
+    ///
+    /// Every member of the type must be present in the value; unknown members are ignored.
+    pub fn hash_struct(&self, name: &str, value: &Value) -> Result<Bytes32, Error> {
+        let fields = self.struct_fields(name)?;
+        let value = value.as_object().ok_or(Error::InvalidTypedData)?;
+
+        let mut hasher = Keccak256Hasher::default();
+
+        hasher.input(self.type_hash(name)?);
+
+        for field in fields.iter() {
+            let member = value.get(&field.name).ok_or(Error::InvalidTypedData)?;
+
+            hasher.input(self.encode_value(&field.ty, member)?);
+        }
+
+        Ok(hasher.finalize())
+    }
+
+    /// Hash of the domain struct
+    pub fn domain_separator(&self) -> Result<Bytes32, Error> {
+        self.hash_struct(DOMAIN_TYPE, &self.domain)
+    }
+
+    /// Message to be signed: `keccak256("\x19\x01" || domainSeparator || hashStruct(message))`
+    pub fn message(&self) -> Result<Message, Error> {
+        let domain = self.domain_separator()?;
+        let message = self.hash_struct(&self.primary_type, &self.message)?;
+
+        Ok(Keccak256Hasher::default()
+            .chain([0x19, 0x01])
+            .chain(domain)
+            .chain(message)
+            .into())
+    }
+
+    // Members of a struct type, inferring the domain type from its value if not provided
+    fn struct_fields(&self, name: &str) -> Result<Vec<Field>, Error> {
+        if let Some(fields) = self.types.get(name) {
+            return Ok(fields.clone());
+        }
+
+        if name != DOMAIN_TYPE {
+            return Err(Error::InvalidTypedData);
+        }
+
+        let domain = self.domain.as_object().ok_or(Error::InvalidTypedData)?;
+
+        Ok(DOMAIN_FIELDS
+            .iter()
+            .filter(|(name, _)| domain.contains_key(*name))
+            .map(|(name, ty)| Field::new(*name, *ty))
+            .collect())
+    }
+
+    fn dependencies<'a>(&'a self, ty: &'a str, found: &mut BTreeSet<&'a str>) {
+        let ty = element_type(ty);
+
+        if let Some(fields) = self.types.get(ty) {
+            if found.insert(ty) {
+                fields
+                    .iter()
+                    .for_each(|field| self.dependencies(&field.ty, found));
+            }
+        }
+    }
+
+    fn encode_struct_type(encoded: &mut String, name: &str, fields: &[Field]) {
+        encoded.push_str(name);
+        encoded.push('(');
+
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                encoded.push(',');
+            }
+
+            encoded.push_str(&field.ty);
+            encoded.push(' ');
+            encoded.push_str(&field.name);
+        }
+
+        encoded.push(')');
+    }
+
+    // Encode a member value into its 32-bytes word, as `encodeData` of the specification
+    fn encode_value(&self, ty: &str, value: &Value) -> Result<[u8; 32], Error> {
+        if let Some((element, len)) = array_type(ty) {
+            let values = value.as_array().ok_or(Error::InvalidTypedData)?;
+
+            if len.map(|len| len != values.len()).unwrap_or(false) {
+                return Err(Error::InvalidTypedData);
+            }
+
+            let mut hasher = Keccak256Hasher::default();
+
+            for value in values {
+                hasher.input(self.encode_value(element, value)?);
+            }
+
+            return Ok(*hasher.finalize());
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value).map(Into::into);
+        }
+
+        let mut word = [0u8; 32];
+
+        match ty {
+            "string" => {
+                let value = value.as_str().ok_or(Error::InvalidTypedData)?;
+
+                word = *keccak256(value);
+            }
+
+            "bytes" => {
+                let value = parse_bytes(value)?;
+
+                word = *keccak256(value);
+            }
+
+            "address" => {
+                let value = value.as_str().ok_or(Error::InvalidTypedData)?;
+                let address = Address::from_str(value).map_err(|_| Error::InvalidTypedData)?;
+
+                word[32 - Address::LEN..].copy_from_slice(address.as_ref());
+            }
+
+            "bool" => {
+                let value = value.as_bool().ok_or(Error::InvalidTypedData)?;
+
+                word[31] = value as u8;
+            }
+
+            ty if ty.starts_with("bytes") => {
+                let len = type_size(&ty[5..])
+                    .filter(|len| (1..=32).contains(len))
+                    .ok_or(Error::InvalidTypedData)?;
+                let value = parse_bytes(value)?;
+
+                if value.len() != len {
+                    return Err(Error::InvalidTypedData);
+                }
+
+                word[..len].copy_from_slice(&value);
+            }
+
+            ty if ty.starts_with("uint") => {
+                let bits = type_size(&ty[4..])
+                    .filter(is_integer_size)
+                    .ok_or(Error::InvalidTypedData)?;
+                let (negative, magnitude) = parse_integer(value)?;
+
+                if negative || bit_len(&magnitude) > bits {
+                    return Err(Error::InvalidTypedData);
+                }
+
+                word = magnitude;
+            }
+
+            ty if ty.starts_with("int") => {
+                let bits = type_size(&ty[3..])
+                    .filter(is_integer_size)
+                    .ok_or(Error::InvalidTypedData)?;
+                let (negative, magnitude) = parse_integer(value)?;
+
+                // The range of a signed integer is `[-2^(bits - 1), 2^(bits - 1) - 1]`
+                let len = bit_len(&magnitude);
+                let min = len == bits && magnitude.iter().map(|b| b.count_ones()).sum::<u32>() == 1;
+
+                if len >= bits && !(negative && min) {
+                    return Err(Error::InvalidTypedData);
+                }
+
+                word = if negative {
+                    negate(&magnitude)
+                } else {
+                    magnitude
+                };
+            }
+
+            _ => return Err(Error::InvalidTypedData),
+        }
+
+        Ok(word)
+    }
+}
+
+impl TryFrom<&TypedData> for Message {
+    type Error = Error;
+
+    fn try_from(data: &TypedData) -> Result<Self, Self::Error> {
+        data.message()
+    }
+}
+
+impl FromStr for TypedData {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+// Split an array type into its element type and its length, if fixed
+fn array_type(ty: &str) -> Option<(&str, Option<usize>)> {
+    let ty = ty.strip_suffix(']')?;
+    let open = ty.rfind('[')?;
+    let len = &ty[open + 1..];
+
+    let len = if len.is_empty() {
+        None
+    } else {
+        Some(len.parse().ok()?)
+    };
+
+    Some((&ty[..open], len))
+}
+
+// Element type of a possibly multidimensional array type
+fn element_type(mut ty: &str) -> &str {
+    while let Some((element, _)) = array_type(ty) {
+        ty = element;
+    }
+
+    ty
+}
+
+// Size of `bytesN`, `uintN` and `intN`
+fn type_size(size: &str) -> Option<usize> {
+    size.parse().ok()
+}
+
+fn is_integer_size(bits: &usize) -> bool {
+    (8..=256).contains(bits) && bits.is_multiple_of(8)
+}
+
+fn parse_bytes(value: &Value) -> Result<Vec<u8>, Error> {
+    let value = value.as_str().ok_or(Error::InvalidTypedData)?;
+    let value = value.strip_prefix("0x").ok_or(Error::InvalidTypedData)?;
+
+    if !value.len().is_multiple_of(2) {
+        return Err(Error::InvalidTypedData);
+    }
+
+    let mut bytes = vec![0u8; value.len() / 2];
+
+    decode_hex(value.as_bytes(), &mut bytes).ok_or(Error::InvalidTypedData)?;
+
+    Ok(bytes)
+}
+
+// Parse an integer as its sign and 256-bits big-endian magnitude.
+//
+// JSON numbers are accepted if they fit 64 bits; larger values are provided as decimal or `0x`
+// hex strings.
+fn parse_integer(value: &Value) -> Result<(bool, [u8; 32]), Error> {
+    let mut magnitude = [0u8; 32];
+
+    if let Some(n) = value.as_u64() {
+        magnitude[24..].copy_from_slice(&n.to_be_bytes());
+
+        return Ok((false, magnitude));
+    }
+
+    if let Some(n) = value.as_i64() {
+        magnitude[24..].copy_from_slice(&n.unsigned_abs().to_be_bytes());
+
+        return Ok((n < 0, magnitude));
+    }
+
+    let value = value.as_str().ok_or(Error::InvalidTypedData)?;
+
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+
+    let (radix, digits) = match value.strip_prefix("0x") {
+        Some(digits) => (16, digits),
+        None => (10, value),
+    };
+
+    if digits.is_empty() {
+        return Err(Error::InvalidTypedData);
+    }
+
+    for c in digits.chars() {
+        let digit = c.to_digit(radix).ok_or(Error::InvalidTypedData)?;
+
+        // `magnitude = magnitude * radix + digit`, failing on overflow
+        let carry = magnitude.iter_mut().rev().fold(digit, |carry, b| {
+            let n = *b as u32 * radix + carry;
+
+            *b = n as u8;
+
+            n >> 8
+        });
+
+        if carry != 0 {
+            return Err(Error::InvalidTypedData);
+        }
+    }
+
+    Ok((negative && magnitude != [0u8; 32], magnitude))
+}
+
+fn bit_len(n: &[u8; 32]) -> usize {
+    n.iter()
+        .position(|b| *b != 0)
+        .map(|i| (32 - i) * 8 - n[i].leading_zeros() as usize)
+        .unwrap_or(0)
+}
+
+// Two's complement negation
+fn negate(n: &[u8; 32]) -> [u8; 32] {
+    let mut negated = [0u8; 32];
+    let mut carry = 1u16;
+
+    for (r, b) in negated.iter_mut().zip(n.iter()).rev() {
+        let sum = (!*b) as u16 + carry;
+
+        *r = sum as u8;
+        carry = sum >> 8;
+    }
+
+    negated
+}
@@ -1,13 +1,122 @@
+use crate::Error;
+
 use fuel_types::Bytes32;
-use sha2::{digest::Update, Digest, Sha256};
+use sha2::digest::consts::U32;
+use sha2::digest::{Digest, FixedOutputReset};
+use sha2::{Sha256, Sha512_256};
+
+use zeroize::Zeroize;
+
+use core::{fmt, iter};
+
+/// Cryptographic hash function with a 32-bytes digest
+///
+/// The provided methods mirror the inherent API of [`Hasher`], so generic code can be written
+/// over the hash algorithm.
+pub trait CryptoHasher: Default + Clone {
+    /// Length of the output
+    const OUTPUT_LEN: usize = Bytes32::LEN;
+
+    /// Append data to the hasher
+    fn input<B>(&mut self, data: B)
+    where
+        B: AsRef<[u8]>;
+
+    /// Reset the hasher to the default state
+    fn reset(&mut self);
+
+    /// Consume the hasher, returning the digest
+    fn finalize(self) -> Bytes32;
+
+    /// Consume, append data and return the hasher
+    fn chain<B>(mut self, data: B) -> Self
+    where
+        B: AsRef<[u8]>,
+    {
+        self.input(data);
+
+        self
+    }
+
+    /// Consume, append the items of the iterator and return the hasher
+    fn extend_chain<B, I>(mut self, iter: I) -> Self
+    where
+        B: AsRef<[u8]>,
+        I: IntoIterator<Item = B>,
+    {
+        iter.into_iter().for_each(|b| self.input(b));
+
+        self
+    }
+
+    /// Hash the provided data, returning its digest
+    fn hash<B>(data: B) -> Bytes32
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::default().chain(data).finalize()
+    }
+
+    /// Return the digest without consuming the hasher
+    fn digest(&self) -> Bytes32 {
+        self.clone().finalize()
+    }
+}
+
+/// Hasher backed by an implementation of [`Digest`] with a 32-bytes output
+#[derive(Debug, Default, Clone)]
+pub struct DigestHasher<D>(D);
+
+/// Standard hasher, backed by SHA-256
+pub type Hasher = DigestHasher<Sha256>;
+
+/// SHA-512/256 hasher, as defined in FIPS 180-4
+pub type Sha512_256Hasher = DigestHasher<Sha512_256>;
 
-use core::iter;
+/// Keccak-256 hasher, as used by Ethereum
+#[cfg(feature = "keccak")]
+pub type Keccak256Hasher = DigestHasher<sha3::Keccak256>;
 
-/// Standard hasher
+/// BLAKE2b hasher with a 256-bits output, as defined in RFC 7693
+#[cfg(feature = "blake2")]
+pub type Blake2bHasher = DigestHasher<blake2::Blake2b<U32>>;
+
+/// BLAKE3 hasher with its default 256-bits output
+///
+/// BLAKE3 doesn't implement a stable [`Digest`], so the hasher is backed by its native API.
+#[cfg(feature = "blake3")]
 #[derive(Debug, Default, Clone)]
-pub struct Hasher(Sha256);
+pub struct Blake3Hasher(blake3::Hasher);
+
+#[cfg(feature = "blake3")]
+impl CryptoHasher for Blake3Hasher {
+    fn input<B>(&mut self, data: B)
+    where
+        B: AsRef<[u8]>,
+    {
+        self.0.update(data.as_ref());
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    fn finalize(self) -> Bytes32 {
+        <[u8; Bytes32::LEN]>::from(self.0.finalize()).into()
+    }
 
-impl Hasher {
+    fn hash<B>(data: B) -> Bytes32
+    where
+        B: AsRef<[u8]>,
+    {
+        <[u8; Bytes32::LEN]>::from(blake3::hash(data.as_ref())).into()
+    }
+}
+
+impl<D> DigestHasher<D>
+where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
+{
     /// Length of the output
     pub const OUTPUT_LEN: usize = Bytes32::LEN;
 
@@ -16,7 +125,7 @@ impl Hasher {
     where
         B: AsRef<[u8]>,
     {
-        sha2::Digest::update(&mut self.0, data)
+        Digest::update(&mut self.0, data)
     }
 
     /// Consume, append data and return the hasher
@@ -24,7 +133,7 @@ impl Hasher {
     where
         B: AsRef<[u8]>,
     {
-        Self(self.0.chain(data))
+        Self(self.0.chain_update(data))
     }
 
     /// Consume, append the items of the iterator and return the hasher
@@ -40,7 +149,7 @@ impl Hasher {
 
     /// Reset the hasher to the default state
     pub fn reset(&mut self) {
-        self.0.reset();
+        Digest::reset(&mut self.0);
     }
 
     /// Hash the provided data, returning its digest
@@ -48,11 +157,7 @@ impl Hasher {
     where
         B: AsRef<[u8]>,
     {
-        let mut hasher = Sha256::new();
-
-        sha2::Digest::update(&mut hasher, data);
-
-        <[u8; Bytes32::LEN]>::from(hasher.finalize()).into()
+        <[u8; Bytes32::LEN]>::from(D::digest(data)).into()
     }
 
     /// Consume the hasher, returning the digest
@@ -66,23 +171,259 @@ impl Hasher {
     }
 }
 
-impl<B> iter::FromIterator<B> for Hasher
+impl<D> CryptoHasher for DigestHasher<D>
 where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
+{
+    fn input<B>(&mut self, data: B)
+    where
+        B: AsRef<[u8]>,
+    {
+        DigestHasher::input(self, data)
+    }
+
+    fn reset(&mut self) {
+        DigestHasher::reset(self)
+    }
+
+    fn finalize(self) -> Bytes32 {
+        DigestHasher::finalize(self)
+    }
+
+    fn hash<B>(data: B) -> Bytes32
+    where
+        B: AsRef<[u8]>,
+    {
+        DigestHasher::<D>::hash(data)
+    }
+}
+
+impl<D, B> iter::FromIterator<B> for DigestHasher<D>
+where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
     B: AsRef<[u8]>,
 {
     fn from_iter<T>(iter: T) -> Self
     where
         T: IntoIterator<Item = B>,
     {
-        iter.into_iter().fold(Hasher::default(), Hasher::chain)
+        iter.into_iter().fold(Self::default(), Self::chain)
     }
 }
 
-impl<B> Extend<B> for Hasher
+impl<D, B> Extend<B> for DigestHasher<D>
 where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
     B: AsRef<[u8]>,
 {
     fn extend<T: IntoIterator<Item = B>>(&mut self, iter: T) {
         iter.into_iter().for_each(|b| self.input(b))
     }
 }
+
+/// Inner padding of the HMAC key block
+const HMAC_IPAD: u8 = 0x36;
+
+/// Outer padding of the HMAC key block
+const HMAC_OPAD: u8 = 0x5c;
+
+/// HMAC-SHA256 message authentication code, as defined in
+/// <https://www.rfc-editor.org/rfc/rfc2104>
+#[derive(Debug, Clone)]
+pub struct Hmac {
+    inner: Hasher,
+    outer: Hasher,
+}
+
+impl Hmac {
+    /// Block length of SHA-256
+    pub const BLOCK_LEN: usize = 64;
+
+    /// Length of the output
+    pub const OUTPUT_LEN: usize = Bytes32::LEN;
+
+    /// Create a new authenticator. Keys longer than [`Hmac::BLOCK_LEN`] are hashed first.
+    pub fn new<K>(key: K) -> Self
+    where
+        K: AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+        let mut block = [0u8; Self::BLOCK_LEN];
+
+        if key.len() > Self::BLOCK_LEN {
+            block[..Bytes32::LEN].copy_from_slice(Hasher::hash(key).as_ref());
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        block.iter_mut().for_each(|b| *b ^= HMAC_IPAD);
+        let inner = Hasher::default().chain(block);
+
+        block.iter_mut().for_each(|b| *b ^= HMAC_IPAD ^ HMAC_OPAD);
+        let outer = Hasher::default().chain(block);
+
+        block.zeroize();
+
+        Self { inner, outer }
+    }
+
+    /// Authentication code of the provided data
+    pub fn mac<K, B>(key: K, data: B) -> Bytes32
+    where
+        K: AsRef<[u8]>,
+        B: AsRef<[u8]>,
+    {
+        Self::new(key).chain(data).finalize()
+    }
+
+    /// Append data to the authenticator
+    pub fn input<B>(&mut self, data: B)
+    where
+        B: AsRef<[u8]>,
+    {
+        self.inner.input(data)
+    }
+
+    /// Consume, append data and return the authenticator
+    pub fn chain<B>(mut self, data: B) -> Self
+    where
+        B: AsRef<[u8]>,
+    {
+        self.input(data);
+
+        self
+    }
+
+    /// Consume, append the items of the iterator and return the authenticator
+    pub fn extend_chain<B, I>(mut self, iter: I) -> Self
+    where
+        B: AsRef<[u8]>,
+        I: IntoIterator<Item = B>,
+    {
+        self.inner.extend(iter);
+
+        self
+    }
+
+    /// Consume the authenticator, returning the code
+    pub fn finalize(self) -> Bytes32 {
+        self.outer.chain(self.inner.finalize()).finalize()
+    }
+
+    /// Return the code without consuming the authenticator
+    pub fn digest(&self) -> Bytes32 {
+        self.clone().finalize()
+    }
+
+    /// Consume the authenticator, comparing the code with the provided one in constant time
+    pub fn verify(self, code: &Bytes32) -> bool {
+        self.finalize()
+            .iter()
+            .zip(code.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+    }
+}
+
+/// HKDF-SHA256 key derivation function, as defined in
+/// <https://www.rfc-editor.org/rfc/rfc5869>
+#[derive(Clone)]
+pub struct Hkdf {
+    prk: [u8; Bytes32::LEN],
+}
+
+impl Hkdf {
+    /// Maximum length of the output keying material
+    pub const MAX_OUTPUT_LEN: usize = 255 * Bytes32::LEN;
+
+    /// Extract a pseudorandom key from the input keying material. An empty salt is equivalent
+    /// to the zeroed salt of the specification.
+    pub fn new<S, K>(salt: S, ikm: K) -> Self
+    where
+        S: AsRef<[u8]>,
+        K: AsRef<[u8]>,
+    {
+        Self::from_prk(Hmac::mac(salt, ikm))
+    }
+
+    /// Skip the extraction step, using an already pseudorandom key
+    pub fn from_prk(prk: Bytes32) -> Self {
+        Self { prk: prk.into() }
+    }
+
+    /// Pseudorandom key of the extraction step
+    pub fn prk(&self) -> Bytes32 {
+        self.prk.into()
+    }
+
+    /// Expand the pseudorandom key into `okm`, for the given context `info`.
+    ///
+    /// Fails with [`Error::InvalidOutputLength`] if `okm` is longer than
+    /// [`Hkdf::MAX_OUTPUT_LEN`].
+    pub fn expand<I>(&self, info: I, okm: &mut [u8]) -> Result<(), Error>
+    where
+        I: AsRef<[u8]>,
+    {
+        if okm.len() > Self::MAX_OUTPUT_LEN {
+            return Err(Error::InvalidOutputLength);
+        }
+
+        let hmac = Hmac::new(self.prk);
+        let mut block: Option<Bytes32> = None;
+
+        for (i, chunk) in okm.chunks_mut(Bytes32::LEN).enumerate() {
+            let mut t = hmac.clone();
+
+            if let Some(previous) = block {
+                t.input(previous);
+            }
+
+            let t = t.chain(info.as_ref()).chain([i as u8 + 1]).finalize();
+
+            chunk.copy_from_slice(&t[..chunk.len()]);
+            block.replace(t);
+        }
+
+        Ok(())
+    }
+
+    /// Expand the pseudorandom key into 32 bytes, for the given context `info`
+    pub fn expand_bytes32<I>(&self, info: I) -> Bytes32
+    where
+        I: AsRef<[u8]>,
+    {
+        // The first block of the output doesn't depend on a previous one
+        Hmac::new(self.prk).chain(info).chain([1]).finalize()
+    }
+}
+
+impl fmt::Debug for Hkdf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hkdf").finish_non_exhaustive()
+    }
+}
+
+impl Drop for Hkdf {
+    fn drop(&mut self) {
+        self.prk.zeroize();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
+    use super::Hkdf;
+    use crate::{Error, SecretKey};
+
+    impl Hkdf {
+        /// Derive a secret key for the given context `info`.
+        ///
+        /// Fails with [`Error::InvalidSecretKey`] in the negligible case the output isn't in
+        /// the field of the curve.
+        pub fn expand_secret_key<I>(&self, info: I) -> Result<SecretKey, Error>
+        where
+            I: AsRef<[u8]>,
+        {
+            SecretKey::try_from(self.expand_bytes32(info))
+        }
+    }
+}
@@ -1,6 +1,25 @@
 use crate::{Error, PublicKey, SecretKey};
 
 use borrown::Borrown;
+use zeroize::Zeroize;
+
+#[cfg(feature = "json-keystore")]
+mod directory;
+#[cfg(feature = "json-keystore")]
+mod json;
+#[cfg(feature = "json-keystore")]
+mod lock;
+#[cfg(feature = "std")]
+mod memory;
+
+#[cfg(feature = "json-keystore")]
+pub use directory::DirectoryKeystore;
+#[cfg(feature = "json-keystore")]
+pub use json::{EncryptedKey, JsonKeyId, JsonKeystore, Kdf};
+#[cfg(feature = "json-keystore")]
+pub use lock::LockableKeystore;
+#[cfg(feature = "std")]
+pub use memory::{KeyId, MemoryKeystore};
 
 /// Keys container
 pub trait Keystore {
@@ -14,11 +33,11 @@ pub trait Keystore {
     fn secret(&self, id: &Self::KeyId) -> Result<Option<Borrown<'_, SecretKey>>, Self::Error>;
 
     /// Public key for a given id
-    #[cfg(not(feature = "std"))]
+    #[cfg(not(any(feature = "std", feature = "k256")))]
     fn public(&self, id: &Self::KeyId) -> Result<Option<Borrown<'_, PublicKey>>, Self::Error>;
 
     /// Public key for a given id
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "k256"))]
     fn public(&self, id: &Self::KeyId) -> Result<Option<Borrown<'_, PublicKey>>, Self::Error> {
         let secret = self.secret(id)?;
         let public = secret
@@ -28,3 +47,110 @@ pub trait Keystore {
         Ok(public)
     }
 }
+
+/// Token required to read the secrets of a [`SealedKeystore`].
+///
+/// It can only be created by this crate, so the secrets are reachable only by the signers of the
+/// crate, as [`OpaqueSigner`](crate::OpaqueSigner).
+#[derive(Debug)]
+pub struct Seal(());
+
+impl Seal {
+    #[cfg(any(feature = "std", feature = "k256"))]
+    pub(crate) const fn new() -> Self {
+        Self(())
+    }
+}
+
+/// Keys container that doesn't expose its secrets outside of this crate.
+///
+/// Every [`Keystore`] is a sealed keystore; implement this trait instead of [`Keystore`] to
+/// prevent the callers from reading the secret keys.
+pub trait SealedKeystore {
+    /// Keystore error implementation
+    type Error: From<Error>;
+
+    /// Identifier for the keypair
+    type KeyId;
+
+    /// Secret key for a given id. Requires a [`Seal`], so it can't be called outside of this
+    /// crate.
+    ///
+    /// The method names differ from [`Keystore`], so both traits can be in scope.
+    fn sealed_secret(
+        &self,
+        id: &Self::KeyId,
+        seal: Seal,
+    ) -> Result<Option<Borrown<'_, SecretKey>>, Self::Error>;
+
+    /// Public key for a given id
+    fn sealed_public(
+        &self,
+        id: &Self::KeyId,
+    ) -> Result<Option<Borrown<'_, PublicKey>>, Self::Error>;
+
+    /// Call `f` with the secret key for a given id, zeroing the owned copy of the key right
+    /// after. Requires a [`Seal`], so it can't be called outside of this crate.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the keystore doesn't contain the key.
+    fn with_secret<F, T>(&self, id: &Self::KeyId, seal: Seal, f: F) -> Result<T, Self::Error>
+    where
+        F: FnOnce(&SecretKey) -> T,
+    {
+        let secret = self.sealed_secret(id, seal)?.ok_or(Error::KeyNotFound)?;
+        let output = f(secret.as_ref());
+
+        if let Borrown::Owned(mut secret) = secret {
+            secret.zeroize();
+        }
+
+        Ok(output)
+    }
+}
+
+impl<K> SealedKeystore for K
+where
+    K: Keystore,
+{
+    type Error = K::Error;
+    type KeyId = K::KeyId;
+
+    fn sealed_secret(
+        &self,
+        id: &Self::KeyId,
+        _seal: Seal,
+    ) -> Result<Option<Borrown<'_, SecretKey>>, Self::Error> {
+        Keystore::secret(self, id)
+    }
+
+    fn sealed_public(
+        &self,
+        id: &Self::KeyId,
+    ) -> Result<Option<Borrown<'_, PublicKey>>, Self::Error> {
+        Keystore::public(self, id)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K> SealedKeystore for alloc::sync::Arc<K>
+where
+    K: SealedKeystore + ?Sized,
+{
+    type Error = K::Error;
+    type KeyId = K::KeyId;
+
+    fn sealed_secret(
+        &self,
+        id: &Self::KeyId,
+        seal: Seal,
+    ) -> Result<Option<Borrown<'_, SecretKey>>, Self::Error> {
+        K::sealed_secret(self, id, seal)
+    }
+
+    fn sealed_public(
+        &self,
+        id: &Self::KeyId,
+    ) -> Result<Option<Borrown<'_, PublicKey>>, Self::Error> {
+        K::sealed_public(self, id)
+    }
+}
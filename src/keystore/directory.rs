@@ -0,0 +1,245 @@
+use super::json::{EncryptedKey, Kdf};
+use super::memory::{KeyId, MemoryKeystore};
+use crate::{Error, Keystore, PublicKey, SecretKey, Signer};
+
+use borrown::Borrown;
+use fuel_types::Bytes32;
+use zeroize::Zeroize;
+
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{fmt, io};
+
+/// Extension of the key files
+const EXTENSION: &str = "json";
+
+/// Keystore backed by a directory, with one Web3 Secret Storage v3 file per key named after its
+/// label.
+///
+/// Every key is encrypted with the password of the keystore, and decrypted in a
+/// [`MemoryKeystore`] when the directory is opened. The password is kept to encrypt the added
+/// keys, and zeroed when the keystore is dropped.
+pub struct DirectoryKeystore {
+    path: PathBuf,
+    password: Vec<u8>,
+    kdf: Kdf,
+    keys: MemoryKeystore,
+}
+
+impl DirectoryKeystore {
+    /// Open the keystore directory, creating it if missing, and decrypt its keys with the
+    /// provided password. Files without the `json` extension are ignored. The added keys are
+    /// encrypted with `kdf`.
+    ///
+    /// Fails with [`Error::KeystoreNotAvailable`] if the directory can't be read, and with
+    /// [`Error::InvalidPassword`] if a key is encrypted with another password.
+    pub fn open<F, P>(path: F, password: P, kdf: Kdf) -> Result<Self, Error>
+    where
+        F: AsRef<Path>,
+        P: AsRef<[u8]>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let password = password.as_ref().to_vec();
+        let keys = MemoryKeystore::new();
+
+        let mut builder = DirBuilder::new();
+
+        builder.recursive(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+        builder
+            .create(&path)
+            .map_err(|_| Error::KeystoreNotAvailable)?;
+
+        let keystore = Self {
+            path,
+            password,
+            kdf,
+            keys,
+        };
+
+        for entry in fs::read_dir(&keystore.path).map_err(|_| Error::KeystoreNotAvailable)? {
+            let file = entry.map_err(|_| Error::KeystoreNotAvailable)?.path();
+
+            let label = match file.file_stem().and_then(|stem| stem.to_str()) {
+                Some(label) if is_key_file(&file) && is_valid_label(label) => label,
+                _ => continue,
+            };
+
+            let json = fs::read_to_string(&file).map_err(|_| Error::KeystoreNotAvailable)?;
+            let secret = EncryptedKey::from_json(&json)?.decrypt(&keystore.password)?;
+
+            keystore.keys.insert(label, secret)?;
+        }
+
+        Ok(keystore)
+    }
+
+    /// Path of the keystore directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Key derivation function of the added keys
+    pub const fn kdf(&self) -> Kdf {
+        self.kdf
+    }
+
+    /// Keys decrypted in memory
+    pub const fn keys(&self) -> &MemoryKeystore {
+        &self.keys
+    }
+
+    /// Path of the file of a label
+    pub fn key_path(&self, label: &str) -> PathBuf {
+        self.path.join(format!("{label}.{EXTENSION}"))
+    }
+
+    /// Encrypt and add a secret key under the provided label, returning the hash of its public
+    /// key.
+    ///
+    /// The label can contain ASCII alphanumeric characters, `-`, `_` and `.`, but can't start
+    /// with `.`; otherwise, fails with [`Error::InvalidKeyLabel`]. Fails with
+    /// [`Error::KeyAlreadyExists`] if either the label or the key is already stored, and with
+    /// [`Error::KeystoreNotAvailable`] if the file can't be written.
+    pub fn insert<R, L>(&self, rng: &mut R, label: L, secret: SecretKey) -> Result<Bytes32, Error>
+    where
+        R: rand::Rng + ?Sized,
+        L: Into<String>,
+    {
+        let label = label.into();
+
+        if !is_valid_label(&label) {
+            return Err(Error::InvalidKeyLabel);
+        }
+
+        let key = EncryptedKey::encrypt(rng, &secret, &self.password, self.kdf)?;
+        let path = self.key_path(&label);
+        let hash = self.keys.insert(label, secret)?;
+
+        let mut options = OpenOptions::new();
+
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let written = options
+            .open(&path)
+            .and_then(|mut file| file.write_all(key.to_json().as_bytes()));
+
+        if written.is_err() {
+            // Don't remove a file that wasn't created by this call
+            if !matches!(&written, Err(e) if e.kind() == io::ErrorKind::AlreadyExists) {
+                let _ = fs::remove_file(&path);
+            }
+
+            self.keys.remove(&KeyId::Hash(hash))?;
+
+            return Err(Error::KeystoreNotAvailable);
+        }
+
+        Ok(hash)
+    }
+
+    /// Labels and public keys of the stored keys, ordered by label
+    pub fn list(&self) -> Vec<(String, PublicKey)> {
+        self.keys.list()
+    }
+
+    /// Remove the key identified by `id` and delete its file, returning its label.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the key isn't stored, and with
+    /// [`Error::KeystoreNotAvailable`] if the file can't be deleted.
+    pub fn remove(&self, id: &KeyId) -> Result<String, Error> {
+        let label = self.keys.label(id).ok_or(Error::KeyNotFound)?;
+
+        fs::remove_file(self.key_path(&label)).map_err(|_| Error::KeystoreNotAvailable)?;
+
+        self.keys.remove(&KeyId::Label(label))
+    }
+
+    /// Replace the label of the key identified by `id` and rename its file, returning the
+    /// previous label.
+    ///
+    /// Fails with the errors of [`MemoryKeystore::rename`], with [`Error::InvalidKeyLabel`] if
+    /// the label is invalid, and with [`Error::KeystoreNotAvailable`] if the file can't be
+    /// renamed.
+    pub fn rename<L>(&self, id: &KeyId, label: L) -> Result<String, Error>
+    where
+        L: Into<String>,
+    {
+        let label = label.into();
+
+        if !is_valid_label(&label) {
+            return Err(Error::InvalidKeyLabel);
+        }
+
+        let previous = self.keys.rename(id, label.as_str())?;
+
+        if previous == label {
+            return Ok(previous);
+        }
+
+        if fs::rename(self.key_path(&previous), self.key_path(&label)).is_err() {
+            self.keys.rename(&KeyId::Label(label), previous)?;
+
+            return Err(Error::KeystoreNotAvailable);
+        }
+
+        Ok(previous)
+    }
+}
+
+impl fmt::Debug for DirectoryKeystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectoryKeystore")
+            .field("path", &self.path)
+            .field("kdf", &self.kdf)
+            .field("keys", &self.keys)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for DirectoryKeystore {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+impl Keystore for DirectoryKeystore {
+    type Error = Error;
+    type KeyId = KeyId;
+
+    fn secret(&self, id: &KeyId) -> Result<Option<Borrown<'_, SecretKey>>, Error> {
+        self.keys.secret(id)
+    }
+
+    fn public(&self, id: &KeyId) -> Result<Option<Borrown<'_, PublicKey>>, Error> {
+        self.keys.public(id)
+    }
+}
+
+impl Signer for DirectoryKeystore {
+    type Error = Error;
+    type Keystore = Self;
+
+    fn keystore(&self) -> Result<&Self, Error> {
+        Ok(self)
+    }
+}
+
+fn is_key_file(path: &Path) -> bool {
+    path.is_file() && path.extension().and_then(|e| e.to_str()) == Some(EXTENSION)
+}
+
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && !label.starts_with('.')
+        && label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
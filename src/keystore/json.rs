@@ -0,0 +1,584 @@
+//! Web3 Secret Storage v3 keystore files, as defined in
+//! <https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/>
+
+use crate::ethereum::{keccak256, Address};
+use crate::{Error, Hmac, Keystore, PublicKey, SecretKey, Signer};
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use borrown::Borrown;
+use fuel_types::Bytes32;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use zeroize::Zeroize;
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{fmt, str};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// Length of the derived key: the first half is the cipher key, and the second half is the MAC key
+const DERIVED_KEY_LEN: usize = 32;
+
+/// Length of the AES-128-CTR initialization vector
+const IV_LEN: usize = 16;
+
+/// Length of the generated salts
+const SALT_LEN: usize = 32;
+
+/// Upper bound of the scrypt cost `log_n` accepted from a document
+const MAX_SCRYPT_LOG_N: u8 = 20;
+
+/// Upper bound of the scrypt memory, `128 * r * n` bytes, accepted from a document
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+
+/// Upper bound of the scrypt parallelization `p` accepted from a document
+const MAX_SCRYPT_P: u32 = 16;
+
+/// Upper bound of the PBKDF2 iterations `c` accepted from a document
+const MAX_PBKDF2_C: u32 = 10_000_000;
+
+/// Key derivation function of an [`EncryptedKey`]
+///
+/// The parameters parsed from a document are bounded, so a crafted file can't exhaust the memory
+/// or the CPU on decryption: scrypt is limited to `n <= 2^20`, `128 * r * n <= 1 GiB` and
+/// `p <= 16`, and PBKDF2 to `c <= 10^7`. Documents above the bounds are rejected with
+/// [`Error::InvalidEncryptedKey`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kdf {
+    /// scrypt, with the cost `n = 2^log_n`, the block size `r` and the parallelization `p`.
+    ///
+    /// The parameters must satisfy RFC 7914, so `n < 2^(16 * r)`; otherwise the derivation
+    /// fails with [`Error::InvalidEncryptedKey`].
+    Scrypt {
+        /// Base 2 logarithm of the cost
+        log_n: u8,
+        /// Block size
+        r: u32,
+        /// Parallelization
+        p: u32,
+    },
+
+    /// PBKDF2-HMAC-SHA256 with `c` iterations
+    Pbkdf2 {
+        /// Number of iterations
+        c: u32,
+    },
+}
+
+impl Kdf {
+    /// Standard scrypt parameters of go-ethereum: `n = 2^18`, `r = 8`, `p = 1`
+    pub const SCRYPT_STANDARD: Self = Self::Scrypt {
+        log_n: 18,
+        r: 8,
+        p: 1,
+    };
+
+    /// Light scrypt parameters of go-ethereum, for constrained environments: `n = 2^12`,
+    /// `r = 8`, `p = 6`
+    pub const SCRYPT_LIGHT: Self = Self::Scrypt {
+        log_n: 12,
+        r: 8,
+        p: 6,
+    };
+
+    fn derive(&self, password: &[u8], salt: &[u8]) -> Result<[u8; DERIVED_KEY_LEN], Error> {
+        let mut key = [0u8; DERIVED_KEY_LEN];
+
+        match *self {
+            Self::Scrypt { log_n, r, p } => {
+                let params = scrypt::Params::new(log_n, r, p, DERIVED_KEY_LEN)
+                    .map_err(|_| Error::InvalidEncryptedKey)?;
+
+                scrypt::scrypt(password, salt, &params, &mut key)
+                    .map_err(|_| Error::InvalidEncryptedKey)?;
+            }
+
+            Self::Pbkdf2 { c: 0 } => return Err(Error::InvalidEncryptedKey),
+
+            // The derived key has the length of a single SHA-256 block
+            Self::Pbkdf2 { c } => {
+                let prf = Hmac::new(password);
+                let mut u = prf.clone().chain(salt).chain(1u32.to_be_bytes()).finalize();
+
+                key.copy_from_slice(u.as_ref());
+
+                for _ in 1..c {
+                    u = prf.clone().chain(u).finalize();
+                    key.iter_mut().zip(u.iter()).for_each(|(k, u)| *k ^= u);
+                }
+            }
+        }
+
+        Ok(key)
+    }
+
+    fn is_bounded(&self) -> bool {
+        match *self {
+            Self::Scrypt { log_n, r, p } => {
+                log_n <= MAX_SCRYPT_LOG_N
+                    && (128 * r as u64) << log_n <= MAX_SCRYPT_MEMORY
+                    && p <= MAX_SCRYPT_P
+            }
+
+            Self::Pbkdf2 { c } => c <= MAX_PBKDF2_C,
+        }
+    }
+
+    fn to_json(self, salt: &[u8]) -> (&'static str, Value) {
+        let salt = hex::encode(salt);
+
+        match self {
+            Self::Scrypt { log_n, r, p } => (
+                "scrypt",
+                json!({ "dklen": DERIVED_KEY_LEN, "n": 1u64 << log_n, "r": r, "p": p, "salt": salt }),
+            ),
+
+            Self::Pbkdf2 { c } => (
+                "pbkdf2",
+                json!({ "dklen": DERIVED_KEY_LEN, "c": c, "prf": "hmac-sha256", "salt": salt }),
+            ),
+        }
+    }
+
+    fn from_json(kdf: &Value, params: &Value) -> Result<(Self, Vec<u8>), Error> {
+        if params["dklen"].as_u64() != Some(DERIVED_KEY_LEN as u64) {
+            return Err(Error::InvalidEncryptedKey);
+        }
+
+        let u32_param = |name: &str| {
+            params[name]
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or(Error::InvalidEncryptedKey)
+        };
+
+        let kdf = match kdf.as_str() {
+            Some("scrypt") => {
+                let n = params["n"]
+                    .as_u64()
+                    .filter(|n| n.is_power_of_two() && *n > 1)
+                    .ok_or(Error::InvalidEncryptedKey)?;
+
+                Self::Scrypt {
+                    log_n: n.trailing_zeros() as u8,
+                    r: u32_param("r")?,
+                    p: u32_param("p")?,
+                }
+            }
+
+            Some("pbkdf2") if params["prf"].as_str() == Some("hmac-sha256") => {
+                Self::Pbkdf2 { c: u32_param("c")? }
+            }
+
+            _ => return Err(Error::InvalidEncryptedKey),
+        };
+
+        if !kdf.is_bounded() {
+            return Err(Error::InvalidEncryptedKey);
+        }
+
+        let salt = parse_hex(&params["salt"])?;
+
+        Ok((kdf, salt))
+    }
+}
+
+impl Default for Kdf {
+    fn default() -> Self {
+        Self::SCRYPT_STANDARD
+    }
+}
+
+/// Secret key encrypted in the Web3 Secret Storage v3 format, with AES-128-CTR and a
+/// Keccak-256 MAC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedKey {
+    id: Uuid,
+    address: Option<Address>,
+    kdf: Kdf,
+    salt: Vec<u8>,
+    iv: [u8; IV_LEN],
+    ciphertext: [u8; SecretKey::LEN],
+    mac: Bytes32,
+}
+
+impl EncryptedKey {
+    /// Encrypt a secret key with the provided password, generating a random salt, initialization
+    /// vector and id.
+    pub fn encrypt<R, P>(
+        rng: &mut R,
+        secret: &SecretKey,
+        password: P,
+        kdf: Kdf,
+    ) -> Result<Self, Error>
+    where
+        R: rand::Rng + ?Sized,
+        P: AsRef<[u8]>,
+    {
+        let mut salt = vec![0u8; SALT_LEN];
+        let mut iv = [0u8; IV_LEN];
+
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+
+        let mut key = kdf.derive(password.as_ref(), &salt)?;
+        let mut ciphertext: [u8; SecretKey::LEN] = **secret;
+
+        apply_keystream(&key, &iv, &mut ciphertext)?;
+
+        let mac = mac(&key, &ciphertext);
+
+        key.zeroize();
+
+        Ok(Self {
+            id: uuid::Builder::from_random_bytes(rng.gen()).into_uuid(),
+            address: Some(secret.public_key().eth_address()),
+            kdf,
+            salt,
+            iv,
+            ciphertext,
+            mac,
+        })
+    }
+
+    /// Decrypt the secret key with the provided password.
+    ///
+    /// Fails with [`Error::InvalidPassword`] if the MAC doesn't match, and with
+    /// [`Error::InvalidEncryptedKey`] if the decrypted key doesn't match the stored address.
+    pub fn decrypt<P>(&self, password: P) -> Result<SecretKey, Error>
+    where
+        P: AsRef<[u8]>,
+    {
+        let mut key = self.kdf.derive(password.as_ref(), &self.salt)?;
+
+        // Comparison in constant time, since the MAC is keyed with the password
+        let valid = mac(&key, &self.ciphertext)
+            .iter()
+            .zip(self.mac.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+
+        if !valid {
+            key.zeroize();
+
+            return Err(Error::InvalidPassword);
+        }
+
+        let mut plaintext = self.ciphertext;
+        let decrypted = apply_keystream(&key, &self.iv, &mut plaintext);
+
+        key.zeroize();
+        decrypted?;
+
+        let secret = SecretKey::try_from(&plaintext[..]);
+
+        plaintext.zeroize();
+
+        let secret = secret?;
+
+        match self.address {
+            Some(address) if address != secret.public_key().eth_address() => {
+                Err(Error::InvalidEncryptedKey)
+            }
+
+            _ => Ok(secret),
+        }
+    }
+
+    /// Unique id of the key
+    pub const fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    /// Address of the key, if stored in the document
+    pub const fn address(&self) -> Option<&Address> {
+        self.address.as_ref()
+    }
+
+    /// Key derivation function of the password
+    pub const fn kdf(&self) -> Kdf {
+        self.kdf
+    }
+
+    /// Parse a JSON document. The `Crypto` member of older go-ethereum documents is accepted.
+    ///
+    /// The parameters of the key derivation function must be within the bounds of [`Kdf`].
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(json).map_err(|_| Error::InvalidEncryptedKey)?;
+
+        if value["version"].as_u64() != Some(3) {
+            return Err(Error::InvalidEncryptedKey);
+        }
+
+        let crypto = value
+            .get("crypto")
+            .or_else(|| value.get("Crypto"))
+            .ok_or(Error::InvalidEncryptedKey)?;
+
+        if crypto["cipher"].as_str() != Some("aes-128-ctr") {
+            return Err(Error::InvalidEncryptedKey);
+        }
+
+        let (kdf, salt) = Kdf::from_json(&crypto["kdf"], &crypto["kdfparams"])?;
+
+        let iv = parse_hex(&crypto["cipherparams"]["iv"])?
+            .try_into()
+            .map_err(|_| Error::InvalidEncryptedKey)?;
+
+        let ciphertext = parse_hex(&crypto["ciphertext"])?
+            .try_into()
+            .map_err(|_| Error::InvalidEncryptedKey)?;
+
+        let mac = Bytes32::try_from(parse_hex(&crypto["mac"])?.as_slice())
+            .map_err(|_| Error::InvalidEncryptedKey)?;
+
+        let id = value["id"]
+            .as_str()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or(Error::InvalidEncryptedKey)?;
+
+        let address = match &value["address"] {
+            Value::Null => None,
+            Value::String(address) => {
+                Some(address.parse().map_err(|_| Error::InvalidEncryptedKey)?)
+            }
+            _ => return Err(Error::InvalidEncryptedKey),
+        };
+
+        Ok(Self {
+            id,
+            address,
+            kdf,
+            salt,
+            iv,
+            ciphertext,
+            mac,
+        })
+    }
+
+    /// Serialize the key as a JSON document
+    pub fn to_json(&self) -> String {
+        let (kdf, kdfparams) = self.kdf.to_json(&self.salt);
+
+        let mut value = json!({
+            "crypto": {
+                "cipher": "aes-128-ctr",
+                "cipherparams": { "iv": hex::encode(self.iv) },
+                "ciphertext": hex::encode(self.ciphertext),
+                "kdf": kdf,
+                "kdfparams": kdfparams,
+                "mac": hex::encode(self.mac),
+            },
+            "id": self.id.hyphenated().to_string(),
+            "version": 3,
+        });
+
+        if let Some(address) = self.address {
+            value["address"] = Value::String(format!("{:x}", address));
+        }
+
+        value.to_string()
+    }
+}
+
+impl str::FromStr for EncryptedKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+/// Identifier of the key of a [`JsonKeystore`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonKeyId {
+    /// Address derived from the secret key
+    Address(Address),
+
+    /// Unique id of the document
+    Uuid(Uuid),
+}
+
+impl From<Address> for JsonKeyId {
+    fn from(address: Address) -> Self {
+        Self::Address(address)
+    }
+}
+
+impl From<Uuid> for JsonKeyId {
+    fn from(id: Uuid) -> Self {
+        Self::Uuid(id)
+    }
+}
+
+/// Keystore of a single Web3 Secret Storage v3 file, decrypted in memory until dropped
+#[derive(Clone)]
+pub struct JsonKeystore {
+    path: PathBuf,
+    key: EncryptedKey,
+    secret: SecretKey,
+    address: Address,
+}
+
+impl JsonKeystore {
+    /// Read and decrypt a keystore file.
+    ///
+    /// Fails with [`Error::KeystoreNotAvailable`] if the file can't be read.
+    pub fn open<F, P>(path: F, password: P) -> Result<Self, Error>
+    where
+        F: AsRef<Path>,
+        P: AsRef<[u8]>,
+    {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|_| Error::KeystoreNotAvailable)?;
+        let key = EncryptedKey::from_json(&json)?;
+        let secret = key.decrypt(password)?;
+
+        Ok(Self::new(path.to_path_buf(), key, secret))
+    }
+
+    /// Encrypt a secret key into a new keystore file, readable only by its owner on Unix.
+    ///
+    /// Fails with [`Error::KeystoreNotAvailable`] if the file already exists or can't be written.
+    pub fn create<F, R, P>(
+        path: F,
+        rng: &mut R,
+        secret: &SecretKey,
+        password: P,
+        kdf: Kdf,
+    ) -> Result<Self, Error>
+    where
+        F: AsRef<Path>,
+        R: rand::Rng + ?Sized,
+        P: AsRef<[u8]>,
+    {
+        let path = path.as_ref();
+        let key = EncryptedKey::encrypt(rng, secret, password, kdf)?;
+
+        let mut options = OpenOptions::new();
+
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        options
+            .open(path)
+            .and_then(|mut file| file.write_all(key.to_json().as_bytes()))
+            .map_err(|_| Error::KeystoreNotAvailable)?;
+
+        Ok(Self::new(path.to_path_buf(), key, *secret))
+    }
+
+    fn new(path: PathBuf, key: EncryptedKey, secret: SecretKey) -> Self {
+        let address = secret.public_key().eth_address();
+
+        Self {
+            path,
+            key,
+            secret,
+            address,
+        }
+    }
+
+    /// Path of the keystore file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encrypted key, as stored in the file
+    pub const fn encrypted_key(&self) -> &EncryptedKey {
+        &self.key
+    }
+
+    /// Address of the key
+    pub const fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Unique id of the key
+    pub const fn id(&self) -> &Uuid {
+        self.key.id()
+    }
+
+    /// Public key of the keystore
+    pub fn public_key(&self) -> PublicKey {
+        self.secret.public_key()
+    }
+
+    fn contains(&self, id: &JsonKeyId) -> bool {
+        match id {
+            JsonKeyId::Address(address) => address == &self.address,
+            JsonKeyId::Uuid(id) => id == self.key.id(),
+        }
+    }
+}
+
+impl fmt::Debug for JsonKeystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonKeystore")
+            .field("path", &self.path)
+            .field("address", &self.address)
+            .field("id", self.key.id())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for JsonKeystore {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Keystore for JsonKeystore {
+    type Error = Error;
+    type KeyId = JsonKeyId;
+
+    fn secret(&self, id: &JsonKeyId) -> Result<Option<Borrown<'_, SecretKey>>, Error> {
+        Ok(self.contains(id).then_some(Borrown::Borrowed(&self.secret)))
+    }
+}
+
+impl Signer for JsonKeystore {
+    type Error = Error;
+    type Keystore = Self;
+
+    fn keystore(&self) -> Result<&Self, Error> {
+        Ok(self)
+    }
+}
+
+fn apply_keystream(
+    key: &[u8; DERIVED_KEY_LEN],
+    iv: &[u8; IV_LEN],
+    data: &mut [u8],
+) -> Result<(), Error> {
+    let mut cipher =
+        Aes128Ctr::new_from_slices(&key[..16], iv).map_err(|_| Error::InvalidEncryptedKey)?;
+
+    cipher.apply_keystream(data);
+
+    Ok(())
+}
+
+// MAC of the ciphertext: `keccak256(key[16..32] || ciphertext)`
+fn mac(key: &[u8; DERIVED_KEY_LEN], ciphertext: &[u8]) -> Bytes32 {
+    let mut data = [0u8; 16 + SecretKey::LEN];
+
+    data[..16].copy_from_slice(&key[16..]);
+    data[16..].copy_from_slice(ciphertext);
+
+    let mac = keccak256(data);
+
+    data.zeroize();
+
+    mac
+}
+
+fn parse_hex(value: &Value) -> Result<Vec<u8>, Error> {
+    let hex = value.as_str().ok_or(Error::InvalidEncryptedKey)?;
+
+    hex::decode(hex.strip_prefix("0x").unwrap_or(hex)).map_err(|_| Error::InvalidEncryptedKey)
+}
@@ -0,0 +1,358 @@
+use super::json::{EncryptedKey, Kdf};
+use super::memory::{KeyId, MemoryKeystore};
+use crate::{Error, Keystore, PublicKey, SecretKey, Signer};
+
+use borrown::Borrown;
+use fuel_types::Bytes32;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Entry {
+    label: String,
+    public: PublicKey,
+    key: EncryptedKey,
+}
+
+struct Unlocked {
+    keys: MemoryKeystore,
+    until: Option<Instant>,
+}
+
+impl Unlocked {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.until.is_some_and(|until| now >= until)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    entries: BTreeMap<Bytes32, Entry>,
+    labels: BTreeMap<String, Bytes32>,
+    unlocked: Option<Unlocked>,
+}
+
+impl State {
+    fn hash(&self, id: &KeyId) -> Option<Bytes32> {
+        match id {
+            KeyId::Hash(hash) => self.entries.contains_key(hash).then_some(*hash),
+            KeyId::Label(label) => self.labels.get(label).copied(),
+        }
+    }
+
+    fn unlocked(&self) -> Result<&MemoryKeystore, Error> {
+        self.unlocked
+            .as_ref()
+            .filter(|unlocked| !unlocked.is_expired(Instant::now()))
+            .map(|unlocked| &unlocked.keys)
+            .ok_or(Error::KeystoreNotAvailable)
+    }
+
+    // Zero the secrets of an expired unlock, returning whether the keystore is still unlocked
+    fn relock_expired(&mut self) -> bool {
+        if self.unlocked().is_ok() {
+            return true;
+        }
+
+        self.unlocked.take();
+
+        false
+    }
+}
+
+// Wait for the expiry of an unlock and zero its secrets, unless the keystore was dropped
+fn relock_at(state: Weak<RwLock<State>>, until: Instant) {
+    thread::sleep(until.saturating_duration_since(Instant::now()));
+
+    if let Some(state) = state.upgrade() {
+        state
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .relock_expired();
+    }
+}
+
+/// Keystore that holds its secrets encrypted in memory, and decrypts them only for the duration
+/// of an [`unlock`](LockableKeystore::unlock).
+///
+/// While locked, the secrets can't be read and [`Signer::sign`] fails with
+/// [`Error::KeystoreNotAvailable`]; the public keys remain available. The decrypted secrets are
+/// zeroed by [`lock`](LockableKeystore::lock), or when the unlock expires.
+///
+/// Every key is encrypted with its own salt, so an unlock runs the key derivation function once
+/// per key.
+pub struct LockableKeystore {
+    kdf: Kdf,
+    state: Arc<RwLock<State>>,
+}
+
+impl LockableKeystore {
+    /// Create an empty, locked keystore. The added keys are encrypted with `kdf`.
+    pub fn new(kdf: Kdf) -> Self {
+        Self {
+            kdf,
+            state: Arc::default(),
+        }
+    }
+
+    // The state is never left inconsistent, so a poisoned lock is recovered
+    fn read(&self) -> RwLockReadGuard<'_, State> {
+        self.state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, State> {
+        self.state.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Key derivation function of the added keys
+    pub const fn kdf(&self) -> Kdf {
+        self.kdf
+    }
+
+    /// Encrypt and add a secret key under the provided label, returning the hash of its public
+    /// key. If the keystore is unlocked, the key is available until the keystore is locked.
+    ///
+    /// All the keys share the same password: fails with [`Error::InvalidPassword`] if the
+    /// password doesn't decrypt the stored keys, and with [`Error::KeyAlreadyExists`] if either
+    /// the label or the key is already stored.
+    pub fn insert<R, L, P>(
+        &self,
+        rng: &mut R,
+        label: L,
+        secret: SecretKey,
+        password: P,
+    ) -> Result<Bytes32, Error>
+    where
+        R: rand::Rng + ?Sized,
+        L: Into<String>,
+        P: AsRef<[u8]>,
+    {
+        let label = label.into();
+        let password = password.as_ref();
+        let public = secret.public_key();
+        let hash = public.hash();
+
+        let key = EncryptedKey::encrypt(rng, &secret, password, self.kdf)?;
+
+        // Derive outside of the lock, so the keystore remains available
+        let existing = self.read().entries.values().next().map(|e| e.key.clone());
+
+        if let Some(existing) = existing {
+            existing.decrypt(password)?;
+        }
+
+        let mut state = self.write();
+
+        if state.entries.contains_key(&hash) || state.labels.contains_key(&label) {
+            return Err(Error::KeyAlreadyExists);
+        }
+
+        if let Ok(keys) = state.unlocked() {
+            keys.insert(label.as_str(), secret)?;
+        }
+
+        state.labels.insert(label.clone(), hash);
+        state.entries.insert(hash, Entry { label, public, key });
+
+        Ok(hash)
+    }
+
+    /// Remove the key identified by `id`, returning its label. The keystore can be locked.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the key isn't stored.
+    pub fn remove(&self, id: &KeyId) -> Result<String, Error> {
+        let mut state = self.write();
+
+        let hash = state.hash(id).ok_or(Error::KeyNotFound)?;
+        let entry = state.entries.remove(&hash).ok_or(Error::KeyNotFound)?;
+
+        state.labels.remove(&entry.label);
+
+        if let Some(unlocked) = &state.unlocked {
+            let _ = unlocked.keys.remove(&KeyId::Hash(hash));
+        }
+
+        Ok(entry.label)
+    }
+
+    /// Labels and public keys of the stored keys, ordered by label. The keystore can be locked.
+    pub fn list(&self) -> Vec<(String, PublicKey)> {
+        let state = self.read();
+
+        state
+            .labels
+            .iter()
+            .filter_map(|(label, hash)| {
+                state
+                    .entries
+                    .get(hash)
+                    .map(|entry| (label.clone(), entry.public))
+            })
+            .collect()
+    }
+
+    /// Number of stored keys
+    pub fn len(&self) -> usize {
+        self.read().entries.len()
+    }
+
+    /// Check if the keystore is empty
+    pub fn is_empty(&self) -> bool {
+        self.read().entries.is_empty()
+    }
+
+    /// Decrypt the secrets for the provided duration, replacing a previous unlock. A duration
+    /// too large to be represented never expires.
+    ///
+    /// A thread is spawned to zero the secrets when the unlock expires. On targets without
+    /// threads, the expired secrets are rejected, but remain in memory until the next access to
+    /// the keystore or a call to [`lock`](LockableKeystore::lock).
+    ///
+    /// Fails with [`Error::InvalidPassword`] if the password doesn't decrypt the stored keys,
+    /// in which case the keystore is locked.
+    pub fn unlock<P>(&self, password: P, duration: Duration) -> Result<(), Error>
+    where
+        P: AsRef<[u8]>,
+    {
+        let password = password.as_ref();
+
+        // Decrypt outside of the lock, so the keystore remains available
+        let encrypted: Vec<(String, EncryptedKey)> = self
+            .read()
+            .entries
+            .values()
+            .map(|entry| (entry.label.clone(), entry.key.clone()))
+            .collect();
+
+        let keys = MemoryKeystore::new();
+        let decrypted = encrypted.into_iter().try_for_each(|(label, key)| {
+            keys.insert(label, key.decrypt(password)?)?;
+
+            Ok(())
+        });
+
+        let mut state = self.write();
+
+        if let Err(e) = decrypted {
+            state.unlocked.take();
+
+            return Err(e);
+        }
+
+        // Keys removed or added during the decryption
+        for (label, public) in keys.list() {
+            if !state.entries.contains_key(&public.hash()) {
+                keys.remove(&KeyId::Label(label))?;
+            }
+        }
+
+        for (hash, entry) in state.entries.iter() {
+            if !keys.contains(&KeyId::Hash(*hash)) {
+                keys.insert(entry.label.as_str(), entry.key.decrypt(password)?)?;
+            }
+        }
+
+        let until = Instant::now().checked_add(duration);
+
+        state.unlocked.replace(Unlocked { keys, until });
+
+        if let Some(until) = until {
+            let state = Arc::downgrade(&self.state);
+
+            // Without threads, the secrets are zeroed lazily
+            let _ = thread::Builder::new()
+                .name("keystore-relock".into())
+                .spawn(move || relock_at(state, until));
+        }
+
+        Ok(())
+    }
+
+    /// Zero the decrypted secrets
+    pub fn lock(&self) {
+        self.write().unlocked.take();
+    }
+
+    /// Check if the secrets are available, zeroing them if the unlock expired
+    pub fn is_unlocked(&self) -> bool {
+        if self.read().unlocked().is_ok() {
+            return true;
+        }
+
+        // The keystore might have been unlocked again since the read guard was released
+        self.write().relock_expired()
+    }
+
+    /// Time remaining until the keystore is locked, or `None` if it is locked or never expires
+    pub fn unlocked_for(&self) -> Option<Duration> {
+        self.read()
+            .unlocked
+            .as_ref()
+            .and_then(|unlocked| unlocked.until)
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+impl Default for LockableKeystore {
+    fn default() -> Self {
+        Self::new(Kdf::default())
+    }
+}
+
+impl fmt::Debug for LockableKeystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.read();
+
+        f.debug_struct("LockableKeystore")
+            .field("kdf", &self.kdf)
+            .field("labels", &state.labels.keys())
+            .field("decrypted", &state.unlocked.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Keystore for LockableKeystore {
+    type Error = Error;
+    type KeyId = KeyId;
+
+    fn secret(&self, id: &KeyId) -> Result<Option<Borrown<'_, SecretKey>>, Error> {
+        let secret = self
+            .read()
+            .unlocked()
+            .and_then(|keys| Ok(keys.secret(id)?.map(|secret| secret.into_owned())));
+
+        // Zero the secrets of an expired unlock
+        if secret.is_err() {
+            self.is_unlocked();
+        }
+
+        Ok(secret?.map(Borrown::Owned))
+    }
+
+    fn public(&self, id: &KeyId) -> Result<Option<Borrown<'_, PublicKey>>, Error> {
+        let state = self.read();
+        let public = state
+            .hash(id)
+            .and_then(|hash| state.entries.get(&hash))
+            .map(|entry| Borrown::Owned(entry.public));
+
+        Ok(public)
+    }
+}
+
+impl Signer for LockableKeystore {
+    type Error = Error;
+    type Keystore = Self;
+
+    fn keystore(&self) -> Result<&Self, Error> {
+        if self.is_unlocked() {
+            Ok(self)
+        } else {
+            Err(Error::KeystoreNotAvailable)
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Error, Keystore, PublicKey, SecretKey, Signer};
+
+use borrown::Borrown;
+use fuel_types::Bytes32;
+use zeroize::Zeroize;
+
+use std::collections::BTreeMap;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::{fmt, mem};
+
+/// Identifier of a key stored in a [`MemoryKeystore`] or a
+/// [`DirectoryKeystore`](crate::DirectoryKeystore)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyId {
+    /// Hash of the public key, as in [`PublicKey::hash`]
+    Hash(Bytes32),
+
+    /// Label provided when the key was added
+    Label(String),
+}
+
+impl From<Bytes32> for KeyId {
+    fn from(hash: Bytes32) -> Self {
+        Self::Hash(hash)
+    }
+}
+
+impl From<&PublicKey> for KeyId {
+    fn from(public: &PublicKey) -> Self {
+        Self::Hash(public.hash())
+    }
+}
+
+impl From<String> for KeyId {
+    fn from(label: String) -> Self {
+        Self::Label(label)
+    }
+}
+
+impl From<&str> for KeyId {
+    fn from(label: &str) -> Self {
+        Self::Label(label.into())
+    }
+}
+
+struct Entry {
+    label: String,
+    secret: SecretKey,
+    public: PublicKey,
+}
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+#[derive(Default)]
+struct Keys {
+    entries: BTreeMap<Bytes32, Entry>,
+    labels: BTreeMap<String, Bytes32>,
+}
+
+impl Keys {
+    fn hash(&self, id: &KeyId) -> Option<Bytes32> {
+        match id {
+            KeyId::Hash(hash) => self.entries.contains_key(hash).then_some(*hash),
+            KeyId::Label(label) => self.labels.get(label).copied(),
+        }
+    }
+
+    fn get(&self, id: &KeyId) -> Option<&Entry> {
+        self.hash(id).and_then(|hash| self.entries.get(&hash))
+    }
+}
+
+/// Thread-safe keystore that holds its keys in memory, identified by the hash of their public
+/// key or by a unique label.
+///
+/// The secrets are zeroed when removed or when the keystore is dropped. The secrets returned by
+/// [`Keystore::secret`] are copies, since the keys are behind a lock.
+#[derive(Default)]
+pub struct MemoryKeystore {
+    keys: RwLock<Keys>,
+}
+
+impl MemoryKeystore {
+    /// Create an empty keystore
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The keys are never left in an inconsistent state, so a poisoned lock is recovered
+    fn read(&self) -> RwLockReadGuard<'_, Keys> {
+        self.keys.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Keys> {
+        self.keys.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Add a secret key under the provided label, returning the hash of its public key.
+    ///
+    /// Fails with [`Error::KeyAlreadyExists`] if either the label or the key is already stored.
+    pub fn insert<L>(&self, label: L, secret: SecretKey) -> Result<Bytes32, Error>
+    where
+        L: Into<String>,
+    {
+        let label = label.into();
+        let public = secret.public_key();
+        let hash = public.hash();
+
+        let mut keys = self.write();
+
+        if keys.entries.contains_key(&hash) || keys.labels.contains_key(&label) {
+            return Err(Error::KeyAlreadyExists);
+        }
+
+        keys.labels.insert(label.clone(), hash);
+        keys.entries.insert(
+            hash,
+            Entry {
+                label,
+                secret,
+                public,
+            },
+        );
+
+        Ok(hash)
+    }
+
+    /// Check if the keystore contains the key identified by `id`
+    pub fn contains(&self, id: &KeyId) -> bool {
+        self.read().hash(id).is_some()
+    }
+
+    /// Label of the key identified by `id`
+    pub fn label(&self, id: &KeyId) -> Option<String> {
+        self.read().get(id).map(|entry| entry.label.clone())
+    }
+
+    /// Labels and public keys of the stored keys, ordered by label
+    pub fn list(&self) -> Vec<(String, PublicKey)> {
+        let keys = self.read();
+
+        keys.labels
+            .iter()
+            .filter_map(|(label, hash)| {
+                keys.entries
+                    .get(hash)
+                    .map(|entry| (label.clone(), entry.public))
+            })
+            .collect()
+    }
+
+    /// Remove the key identified by `id`, returning its label.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the key isn't stored.
+    pub fn remove(&self, id: &KeyId) -> Result<String, Error> {
+        let mut keys = self.write();
+
+        let hash = keys.hash(id).ok_or(Error::KeyNotFound)?;
+        let mut entry = keys.entries.remove(&hash).ok_or(Error::KeyNotFound)?;
+
+        keys.labels.remove(&entry.label);
+
+        Ok(mem::take(&mut entry.label))
+    }
+
+    /// Replace the label of the key identified by `id`, returning the previous label.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the key isn't stored, and with
+    /// [`Error::KeyAlreadyExists`] if the label belongs to another key.
+    pub fn rename<L>(&self, id: &KeyId, label: L) -> Result<String, Error>
+    where
+        L: Into<String>,
+    {
+        let label = label.into();
+        let mut keys = self.write();
+
+        let hash = keys.hash(id).ok_or(Error::KeyNotFound)?;
+
+        match keys.labels.get(&label) {
+            Some(h) if h == &hash => return Ok(label),
+            Some(_) => return Err(Error::KeyAlreadyExists),
+            None => (),
+        }
+
+        keys.labels.insert(label.clone(), hash);
+
+        let entry = keys.entries.get_mut(&hash).ok_or(Error::KeyNotFound)?;
+        let previous = mem::replace(&mut entry.label, label);
+
+        keys.labels.remove(&previous);
+
+        Ok(previous)
+    }
+
+    /// Number of stored keys
+    pub fn len(&self) -> usize {
+        self.read().entries.len()
+    }
+
+    /// Check if the keystore is empty
+    pub fn is_empty(&self) -> bool {
+        self.read().entries.is_empty()
+    }
+}
+
+impl fmt::Debug for MemoryKeystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryKeystore")
+            .field("labels", &self.read().labels.keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Keystore for MemoryKeystore {
+    type Error = Error;
+    type KeyId = KeyId;
+
+    fn secret(&self, id: &KeyId) -> Result<Option<Borrown<'_, SecretKey>>, Error> {
+        Ok(self
+            .read()
+            .get(id)
+            .map(|entry| Borrown::Owned(entry.secret)))
+    }
+
+    fn public(&self, id: &KeyId) -> Result<Option<Borrown<'_, PublicKey>>, Error> {
+        Ok(self
+            .read()
+            .get(id)
+            .map(|entry| Borrown::Owned(entry.public)))
+    }
+}
+
+impl Signer for MemoryKeystore {
+    type Error = Error;
+    type Keystore = Self;
+
+    fn keystore(&self) -> Result<&Self, Error> {
+        Ok(self)
+    }
+}
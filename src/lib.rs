@@ -7,10 +7,14 @@
 // https://rust-lang.github.io/api-guidelines/naming.html
 #![allow(clippy::wrong_self_convention)]
 
+#[cfg(any(feature = "alloc", feature = "eip712", feature = "pkcs8"))]
+extern crate alloc;
+
 /// Required export to implement [`Keystore`].
 #[doc(no_inline)]
 pub use borrown;
-/// Required export for using mnemonic keygen on [`SecretKey::new_from_mnemonic`]
+/// Required export for using mnemonic keygen on [`SecretKey::new_from_mnemonic`] and the
+/// derivation paths of [`ExtendedSecretKey`]
 #[cfg(feature = "std")]
 #[doc(no_inline)]
 pub use coins_bip32;
@@ -25,23 +29,76 @@ pub use fuel_types;
 #[doc(no_inline)]
 /// Required export to use randomness features
 pub use rand;
+/// Required export to build the values of [`ethereum::eip712::TypedData`]
+#[cfg(feature = "eip712")]
+#[doc(no_inline)]
+pub use serde_json;
+/// Required export to identify the keys of [`JsonKeystore`]
+#[cfg(feature = "json-keystore")]
+#[doc(no_inline)]
+pub use uuid;
+
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
+#[cfg(feature = "ethereum")]
+pub mod ethereum;
+pub mod merkle;
+#[cfg(feature = "secp256r1")]
+pub mod secp256r1;
 
+#[cfg(feature = "std")]
+mod account;
+mod async_signer;
+#[cfg(feature = "std")]
+mod bip32;
+mod compressed;
+#[cfg(all(feature = "pkcs8", any(feature = "std", feature = "k256")))]
+mod encoding;
 mod error;
 mod hasher;
 mod keystore;
 mod message;
 mod mnemonic;
+#[cfg(any(feature = "std", feature = "k256"))]
+mod opaque_signer;
 mod public;
+mod schnorr;
 mod secret;
 mod signature;
 mod signer;
+#[cfg(any(feature = "ethereum", feature = "std", feature = "k256"))]
+mod util;
 
+pub use async_signer::AsyncSigner;
+pub use compressed::CompressedPublicKey;
 pub use error::Error;
-pub use hasher::Hasher;
-pub use keystore::Keystore;
+pub use hasher::{CryptoHasher, DigestHasher, Hasher, Hkdf, Hmac, Sha512_256Hasher};
+pub use keystore::{Keystore, Seal, SealedKeystore};
 pub use message::Message;
 pub use mnemonic::FuelMnemonic;
 pub use public::PublicKey;
+pub use schnorr::{SchnorrSignature, XOnlyPublicKey};
 pub use secret::SecretKey;
-pub use signature::Signature;
+pub use signature::{DerSignature, Signature};
 pub use signer::Signer;
+
+#[cfg(feature = "std")]
+pub use account::{AccountKeys, FuelAccount, FuelDerivationPath};
+#[cfg(feature = "std")]
+pub use bip32::{ExtendedPublicKey, ExtendedSecretKey};
+#[cfg(feature = "blake2")]
+pub use hasher::Blake2bHasher;
+#[cfg(feature = "blake3")]
+pub use hasher::Blake3Hasher;
+#[cfg(feature = "keccak")]
+pub use hasher::Keccak256Hasher;
+#[cfg(feature = "json-keystore")]
+pub use keystore::{
+    DirectoryKeystore, EncryptedKey, JsonKeyId, JsonKeystore, Kdf, LockableKeystore,
+};
+#[cfg(feature = "std")]
+pub use keystore::{KeyId, MemoryKeystore};
+#[cfg(feature = "std")]
+pub use mnemonic::MnemonicLanguage;
+#[cfg(any(feature = "std", feature = "k256"))]
+pub use opaque_signer::OpaqueSigner;
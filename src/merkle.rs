@@ -0,0 +1,51 @@
+//! Merkle trees over an arbitrary [`CryptoHasher`].
+//!
+//! Leaves and internal nodes are domain separated, as defined in
+//! <https://www.rfc-editor.org/rfc/rfc6962#section-2.1>, so a leaf can't be forged as a node:
+//!
+//! - the empty tree hashes to `H()`;
+//! - a leaf hashes to `H(0x00 || data)`;
+//! - a node hashes to `H(0x01 || left || right)`.
+
+use crate::hasher::CryptoHasher;
+
+use fuel_types::Bytes32;
+
+pub mod binary;
+#[cfg(feature = "alloc")]
+pub mod sparse;
+
+/// Prefix of the leaf data
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix of the concatenated children of an internal node
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Root of an empty tree: the digest of the empty input
+pub fn empty_sum<H>() -> Bytes32
+where
+    H: CryptoHasher,
+{
+    H::hash([])
+}
+
+/// Digest of a leaf: `H(0x00 || data)`
+pub fn leaf_sum<H, B>(data: B) -> Bytes32
+where
+    H: CryptoHasher,
+    B: AsRef<[u8]>,
+{
+    H::default().chain([LEAF_PREFIX]).chain(data).finalize()
+}
+
+/// Digest of an internal node: `H(0x01 || left || right)`
+pub fn node_sum<H>(left: &Bytes32, right: &Bytes32) -> Bytes32
+where
+    H: CryptoHasher,
+{
+    H::default()
+        .chain([NODE_PREFIX])
+        .chain(left)
+        .chain(right)
+        .finalize()
+}
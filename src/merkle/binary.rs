@@ -0,0 +1,321 @@
+//! Binary Merkle tree, compatible with the Merkle Tree Hash of
+//! <https://www.rfc-editor.org/rfc/rfc6962#section-2.1>.
+//!
+//! The root is computed incrementally, with one complete subtree per bit of the number of leaves,
+//! so neither the tree nor the inclusion proofs allocate.
+
+use super::{empty_sum, leaf_sum, node_sum};
+use crate::hasher::CryptoHasher;
+use crate::{Error, Hasher};
+
+use fuel_types::Bytes32;
+
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{fmt, iter};
+
+/// Maximum height of a tree, bounded by the number of leaves
+pub const MAX_HEIGHT: usize = u64::BITS as usize;
+
+/// Incremental binary Merkle tree
+#[derive(Clone)]
+pub struct MerkleTree<H = Hasher> {
+    // Root of the complete subtree of height `h`, set if the bit `h` of `leaves` is set
+    nodes: [Bytes32; MAX_HEIGHT],
+    leaves: u64,
+    proof: Option<MerkleProof>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H> MerkleTree<H>
+where
+    H: CryptoHasher,
+{
+    /// Create an empty tree
+    pub fn new() -> Self {
+        Self {
+            nodes: [Bytes32::zeroed(); MAX_HEIGHT],
+            leaves: 0,
+            proof: None,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Create an empty tree that records the inclusion proof of the leaf of the provided index
+    pub fn with_proof_index(index: u64) -> Self {
+        let mut tree = Self::new();
+
+        tree.proof = Some(MerkleProof::empty(index));
+
+        tree
+    }
+
+    /// Number of pushed leaves
+    pub const fn leaves_count(&self) -> u64 {
+        self.leaves
+    }
+
+    /// Append a leaf to the tree
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree already has `u64::MAX` leaves.
+    pub fn push<B>(&mut self, data: B)
+    where
+        B: AsRef<[u8]>,
+    {
+        assert!(self.leaves < u64::MAX, "The merkle tree is full");
+
+        let leaves = self.leaves;
+        let mut node = leaf_sum::<H, _>(data);
+
+        // Whether the proven leaf is part of the carried subtree
+        let mut carried = matches!(&self.proof, Some(p) if p.index == leaves);
+        let mut height = 0;
+
+        // Merge the subtrees of the same height, as in a binary increment
+        while leaves >> height & 1 == 1 {
+            let left = self.nodes[height];
+
+            if let Some(proof) = self.proof.as_mut() {
+                if carried {
+                    proof.push(left);
+                } else if (proof.index ^ leaves) >> height == 1 {
+                    proof.push(node);
+                    carried = true;
+                }
+            }
+
+            node = node_sum::<H>(&left, &node);
+            height += 1;
+        }
+
+        self.nodes[height] = node;
+        self.leaves += 1;
+    }
+
+    /// Root of the tree
+    pub fn root(&self) -> Bytes32 {
+        self.fold(None)
+    }
+
+    /// Root of the tree and the inclusion proof of the leaf set with
+    /// [`MerkleTree::with_proof_index`].
+    ///
+    /// Returns `None` if no proof index was set, or if the leaf wasn't pushed yet.
+    pub fn prove(&self) -> Option<(Bytes32, MerkleProof)> {
+        let mut proof = self.proof.clone().filter(|p| p.index < self.leaves)?;
+
+        proof.leaves = self.leaves;
+
+        let root = self.fold(Some(&mut proof));
+
+        Some((root, proof))
+    }
+
+    // Merge the complete subtrees from the lowest to the highest, as the right-most subtree
+    // is always the smallest
+    fn fold(&self, mut proof: Option<&mut MerkleProof>) -> Bytes32 {
+        let mut root: Option<Bytes32> = None;
+        let mut carried = false;
+
+        for height in (0..MAX_HEIGHT).filter(|h| self.leaves >> h & 1 == 1) {
+            let node = self.nodes[height];
+            let contains = matches!(&proof, Some(p) if (p.index ^ self.leaves) >> height == 1);
+
+            root = match root {
+                Some(right) => {
+                    if let Some(proof) = proof.as_mut() {
+                        if contains {
+                            proof.push(right);
+                        } else if carried {
+                            proof.push(node);
+                        }
+                    }
+
+                    Some(node_sum::<H>(&node, &right))
+                }
+
+                None => Some(node),
+            };
+
+            carried |= contains;
+        }
+
+        root.unwrap_or_else(empty_sum::<H>)
+    }
+}
+
+impl<H> Default for MerkleTree<H>
+where
+    H: CryptoHasher,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> fmt::Debug for MerkleTree<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MerkleTree")
+            .field("leaves", &self.leaves)
+            .field("proof_index", &self.proof.as_ref().map(|p| p.index))
+            .finish()
+    }
+}
+
+impl<H, B> iter::FromIterator<B> for MerkleTree<H>
+where
+    H: CryptoHasher,
+    B: AsRef<[u8]>,
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = B>,
+    {
+        let mut tree = Self::new();
+
+        tree.extend(iter);
+
+        tree
+    }
+}
+
+impl<H, B> Extend<B> for MerkleTree<H>
+where
+    H: CryptoHasher,
+    B: AsRef<[u8]>,
+{
+    fn extend<T: IntoIterator<Item = B>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|b| self.push(b))
+    }
+}
+
+/// Inclusion proof of a leaf: the sibling digests of its path to the root, from the bottom up.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    index: u64,
+    leaves: u64,
+    set: [Bytes32; MAX_HEIGHT],
+    len: usize,
+}
+
+impl MerkleProof {
+    /// Create a proof from its components
+    ///
+    /// Fails with [`Error::InvalidMerkleProof`] if the index is out of the tree or if the set is
+    /// longer than [`MAX_HEIGHT`]. The proof itself is checked by [`MerkleProof::verify`].
+    pub fn new(index: u64, leaves: u64, set: &[Bytes32]) -> Result<Self, Error> {
+        if index >= leaves || set.len() > MAX_HEIGHT {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let mut proof = Self::empty(index);
+
+        proof.leaves = leaves;
+        set.iter().for_each(|node| proof.push(*node));
+
+        Ok(proof)
+    }
+
+    fn empty(index: u64) -> Self {
+        Self {
+            index,
+            leaves: 0,
+            set: [Bytes32::zeroed(); MAX_HEIGHT],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, node: Bytes32) {
+        self.set[self.len] = node;
+        self.len += 1;
+    }
+
+    /// Index of the proven leaf
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Number of leaves of the tree
+    pub const fn leaves_count(&self) -> u64 {
+        self.leaves
+    }
+
+    /// Sibling digests, from the bottom up
+    pub fn proof_set(&self) -> &[Bytes32] {
+        &self.set[..self.len]
+    }
+
+    /// Check the inclusion of the leaf data under the provided root
+    pub fn verify<H, B>(&self, root: &Bytes32, data: B) -> bool
+    where
+        H: CryptoHasher,
+        B: AsRef<[u8]>,
+    {
+        verify::<H, _>(root, data, self.index, self.leaves, self.proof_set())
+    }
+}
+
+impl Deref for MerkleProof {
+    type Target = [Bytes32];
+
+    fn deref(&self) -> &[Bytes32] {
+        self.proof_set()
+    }
+}
+
+impl AsRef<[Bytes32]> for MerkleProof {
+    fn as_ref(&self) -> &[Bytes32] {
+        self.proof_set()
+    }
+}
+
+impl fmt::Debug for MerkleProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MerkleProof")
+            .field("index", &self.index)
+            .field("leaves", &self.leaves)
+            .field("proof_set", &self.proof_set())
+            .finish()
+    }
+}
+
+/// Check the inclusion of the leaf data at `index` of a tree of `leaves` leaves, as defined in
+/// <https://www.rfc-editor.org/rfc/rfc9162#section-2.1.3.2>
+pub fn verify<H, B>(root: &Bytes32, data: B, index: u64, leaves: u64, proof_set: &[Bytes32]) -> bool
+where
+    H: CryptoHasher,
+    B: AsRef<[u8]>,
+{
+    if index >= leaves {
+        return false;
+    }
+
+    let mut f = index;
+    let mut s = leaves - 1;
+    let mut node = leaf_sum::<H, _>(data);
+
+    for sibling in proof_set {
+        if s == 0 {
+            return false;
+        }
+
+        if f & 1 == 1 || f == s {
+            node = node_sum::<H>(sibling, &node);
+
+            // Skip the levels where the node is the right-most of its subtree, without sibling
+            while f & 1 == 0 && f != 0 {
+                f >>= 1;
+                s >>= 1;
+            }
+        } else {
+            node = node_sum::<H>(&node, sibling);
+        }
+
+        f >>= 1;
+        s >>= 1;
+    }
+
+    s == 0 && node == *root
+}
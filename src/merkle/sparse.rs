@@ -0,0 +1,571 @@
+//! Sparse Merkle tree of 256 levels, keyed by [`Bytes32`].
+//!
+//! Empty subtrees hash to the zeroed placeholder, and a subtree with a single leaf is replaced by
+//! the leaf itself, so the leaves sit at the depth where their key diverges from the other keys:
+//!
+//! - the empty subtree is `[0; 32]`;
+//! - a leaf hashes to `H(0x00 || key || H(value))`;
+//! - a node hashes to `H(0x01 || left || right)`.
+//!
+//! The nodes are addressed by their digest in a [`Storage`] backend.
+
+use super::{node_sum, LEAF_PREFIX};
+use crate::hasher::CryptoHasher;
+use crate::{Error, Hasher};
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use borrown::Borrown;
+use fuel_types::Bytes32;
+
+use core::marker::PhantomData;
+
+/// Depth of the tree, in bits of the key
+pub const DEPTH: usize = Bytes32::LEN * 8;
+
+/// Digest of the empty subtree
+pub const PLACEHOLDER: Bytes32 = Bytes32::zeroed();
+
+/// Node of the sparse Merkle tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Node {
+    /// Leaf with the key and the digest of the value
+    Leaf {
+        /// Key of the leaf
+        key: Bytes32,
+        /// Digest of the value
+        value: Bytes32,
+    },
+
+    /// Internal node with the digests of its children
+    Internal {
+        /// Digest of the left child, with the bit `0` at the depth of the node
+        left: Bytes32,
+        /// Digest of the right child, with the bit `1` at the depth of the node
+        right: Bytes32,
+    },
+}
+
+impl Node {
+    /// Digest of the node
+    pub fn hash<H>(&self) -> Bytes32
+    where
+        H: CryptoHasher,
+    {
+        match self {
+            Self::Leaf { key, value } => leaf_sum::<H>(key, value),
+            Self::Internal { left, right } => node_sum::<H>(left, right),
+        }
+    }
+}
+
+/// Nodes container of a sparse Merkle tree
+pub trait Storage {
+    /// Storage error implementation
+    type Error: From<Error>;
+
+    /// Node for a given digest
+    fn get(&self, hash: &Bytes32) -> Result<Option<Borrown<'_, Node>>, Self::Error>;
+
+    /// Store a node under its digest
+    fn insert(&mut self, hash: &Bytes32, node: &Node) -> Result<(), Self::Error>;
+
+    /// Remove the node of a given digest
+    fn remove(&mut self, hash: &Bytes32) -> Result<(), Self::Error>;
+}
+
+/// In-memory storage of the nodes
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryStorage {
+    nodes: BTreeMap<Bytes32, Node>,
+}
+
+impl MemoryStorage {
+    /// Create an empty storage
+    pub const fn new() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    /// Number of stored nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if the storage is empty
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl Storage for MemoryStorage {
+    type Error = Error;
+
+    fn get(&self, hash: &Bytes32) -> Result<Option<Borrown<'_, Node>>, Error> {
+        Ok(self.nodes.get(hash).map(Borrown::Borrowed))
+    }
+
+    fn insert(&mut self, hash: &Bytes32, node: &Node) -> Result<(), Error> {
+        self.nodes.insert(*hash, *node);
+
+        Ok(())
+    }
+
+    fn remove(&mut self, hash: &Bytes32) -> Result<(), Error> {
+        self.nodes.remove(hash);
+
+        Ok(())
+    }
+}
+
+// Path of a key from the root
+struct Path {
+    // Siblings, from the top down
+    siblings: Vec<Bytes32>,
+    // Internal nodes, from the top down
+    nodes: Vec<Bytes32>,
+    // Leaf where the path ends, if not empty
+    leaf: Option<(Bytes32, Bytes32, Bytes32)>,
+}
+
+/// Sparse Merkle tree over a [`Storage`] backend
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<S, H = Hasher> {
+    storage: S,
+    root: Bytes32,
+    _hasher: PhantomData<H>,
+}
+
+impl<S, H> SparseMerkleTree<S, H>
+where
+    S: Storage,
+    H: CryptoHasher,
+{
+    /// Create an empty tree
+    pub const fn new(storage: S) -> Self {
+        Self::load(storage, PLACEHOLDER)
+    }
+
+    /// Open the tree of a given root. The nodes are expected to be available in the storage.
+    pub const fn load(storage: S, root: Bytes32) -> Self {
+        Self {
+            storage,
+            root,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Root of the tree
+    pub const fn root(&self) -> Bytes32 {
+        self.root
+    }
+
+    /// Accessor to the storage
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Consume the tree, returning its storage
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Digest of the value of a given key, if present
+    pub fn value_hash(&self, key: &Bytes32) -> Result<Option<Bytes32>, S::Error> {
+        let path = self.path(key)?;
+
+        Ok(path
+            .leaf
+            .filter(|(k, _, _)| k == key)
+            .map(|(_, value, _)| value))
+    }
+
+    /// Insert or update the value of a given key
+    pub fn update<B>(&mut self, key: &Bytes32, value: B) -> Result<(), S::Error>
+    where
+        B: AsRef<[u8]>,
+    {
+        let path = self.path(key)?;
+
+        path.nodes
+            .iter()
+            .try_for_each(|hash| self.storage.remove(hash))?;
+
+        let leaf = Node::Leaf {
+            key: *key,
+            value: H::hash(value),
+        };
+
+        if let Some((_, _, old)) = path.leaf.filter(|(k, _, _)| k == key) {
+            self.storage.remove(&old)?;
+        }
+
+        let mut hash = leaf.hash::<H>();
+
+        self.storage.insert(&hash, &leaf)?;
+
+        // Another key ends at this path: push both leaves down to where the keys diverge
+        if let Some((other, _, other_hash)) = path.leaf.filter(|(k, _, _)| k != key) {
+            let depth = path.siblings.len();
+            let diverge = (depth..DEPTH)
+                .find(|d| bit(key, *d) != bit(&other, *d))
+                .ok_or(Error::InvalidMerkleTree)?;
+
+            hash = self.insert_node(key, diverge, hash, other_hash)?;
+
+            for d in (depth..diverge).rev() {
+                hash = self.insert_node(key, d, hash, PLACEHOLDER)?;
+            }
+        }
+
+        self.root = self.insert_path(key, &path.siblings, hash)?;
+
+        Ok(())
+    }
+
+    /// Remove a given key from the tree. Nothing is changed if the key isn't present.
+    pub fn delete(&mut self, key: &Bytes32) -> Result<(), S::Error> {
+        let path = self.path(key)?;
+
+        let leaf = match path.leaf {
+            Some((k, _, leaf)) if k == *key => leaf,
+            _ => return Ok(()),
+        };
+
+        path.nodes
+            .iter()
+            .try_for_each(|hash| self.storage.remove(hash))?;
+
+        self.storage.remove(&leaf)?;
+
+        // A leaf left alone in its subtree is lifted until it has a sibling
+        let mut hash = PLACEHOLDER;
+        let mut depth = path.siblings.len();
+
+        while depth > 0 {
+            let sibling = path.siblings[depth - 1];
+
+            let lift = if hash == PLACEHOLDER {
+                sibling != PLACEHOLDER && matches!(self.node(&sibling)?, Node::Leaf { .. })
+            } else {
+                sibling == PLACEHOLDER
+            };
+
+            if !lift {
+                break;
+            }
+
+            if hash == PLACEHOLDER {
+                hash = sibling;
+            }
+
+            depth -= 1;
+        }
+
+        self.root = self.insert_path(key, &path.siblings[..depth], hash)?;
+
+        Ok(())
+    }
+
+    /// Inclusion proof of the key if present, or exclusion proof otherwise
+    pub fn prove(&self, key: &Bytes32) -> Result<SparseMerkleProof, S::Error> {
+        let path = self.path(key)?;
+
+        Ok(SparseMerkleProof {
+            siblings: path.siblings,
+            leaf: path.leaf.map(|(key, value, _)| (key, value)),
+        })
+    }
+
+    fn node(&self, hash: &Bytes32) -> Result<Node, S::Error> {
+        let node = self.storage.get(hash)?.ok_or(Error::InvalidMerkleTree)?;
+
+        Ok(*node.as_ref())
+    }
+
+    fn path(&self, key: &Bytes32) -> Result<Path, S::Error> {
+        let mut path = Path {
+            siblings: Vec::new(),
+            nodes: Vec::new(),
+            leaf: None,
+        };
+
+        let mut hash = self.root;
+
+        while hash != PLACEHOLDER {
+            match self.node(&hash)? {
+                Node::Leaf { key, value } => {
+                    path.leaf = Some((key, value, hash));
+
+                    break;
+                }
+
+                Node::Internal { .. } if path.siblings.len() == DEPTH => {
+                    return Err(Error::InvalidMerkleTree.into());
+                }
+
+                Node::Internal { left, right } => {
+                    path.nodes.push(hash);
+
+                    if bit(key, path.siblings.len()) {
+                        path.siblings.push(left);
+                        hash = right;
+                    } else {
+                        path.siblings.push(right);
+                        hash = left;
+                    }
+                }
+            }
+        }
+
+        Ok(path)
+    }
+
+    // Store the internal node at `depth`, where `hash` is the child on the side of `key`
+    fn insert_node(
+        &mut self,
+        key: &Bytes32,
+        depth: usize,
+        hash: Bytes32,
+        sibling: Bytes32,
+    ) -> Result<Bytes32, S::Error> {
+        let node = if bit(key, depth) {
+            Node::Internal {
+                left: sibling,
+                right: hash,
+            }
+        } else {
+            Node::Internal {
+                left: hash,
+                right: sibling,
+            }
+        };
+
+        let hash = node.hash::<H>();
+
+        self.storage.insert(&hash, &node)?;
+
+        Ok(hash)
+    }
+
+    // Store the internal nodes from the bottom of the siblings up to the root
+    fn insert_path(
+        &mut self,
+        key: &Bytes32,
+        siblings: &[Bytes32],
+        mut hash: Bytes32,
+    ) -> Result<Bytes32, S::Error> {
+        for (depth, sibling) in siblings.iter().enumerate().rev() {
+            hash = self.insert_node(key, depth, hash, *sibling)?;
+        }
+
+        Ok(hash)
+    }
+}
+
+/// Inclusion or exclusion proof of a key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    siblings: Vec<Bytes32>,
+    leaf: Option<(Bytes32, Bytes32)>,
+}
+
+impl SparseMerkleProof {
+    /// Sibling digests, from the top down
+    pub fn siblings(&self) -> &[Bytes32] {
+        &self.siblings
+    }
+
+    /// Key and value digest of the leaf where the path of the key ends, if not empty.
+    ///
+    /// For an exclusion proof, this is the leaf of another key that shares the path.
+    pub const fn leaf(&self) -> Option<&(Bytes32, Bytes32)> {
+        self.leaf.as_ref()
+    }
+
+    /// Check the inclusion of the key with the provided value under the root
+    pub fn verify_inclusion<H, B>(&self, root: &Bytes32, key: &Bytes32, value: B) -> bool
+    where
+        H: CryptoHasher,
+        B: AsRef<[u8]>,
+    {
+        match self.leaf {
+            Some((k, v)) if k == *key && v == H::hash(value) => self.verify::<H>(root, key),
+            _ => false,
+        }
+    }
+
+    /// Check the exclusion of the key under the root
+    pub fn verify_exclusion<H>(&self, root: &Bytes32, key: &Bytes32) -> bool
+    where
+        H: CryptoHasher,
+    {
+        match self.leaf {
+            Some((k, _)) if k == *key => false,
+            _ => self.verify::<H>(root, key),
+        }
+    }
+
+    fn verify<H>(&self, root: &Bytes32, key: &Bytes32) -> bool
+    where
+        H: CryptoHasher,
+    {
+        let depth = self.siblings.len();
+
+        if depth > DEPTH {
+            return false;
+        }
+
+        let mut hash = match self.leaf {
+            // The leaf must be on the path of the key
+            Some((k, _)) if (0..depth).any(|d| bit(key, d) != bit(&k, d)) => return false,
+            Some((k, v)) => leaf_sum::<H>(&k, &v),
+            None => PLACEHOLDER,
+        };
+
+        for (d, sibling) in self.siblings.iter().enumerate().rev() {
+            hash = if bit(key, d) {
+                node_sum::<H>(sibling, &hash)
+            } else {
+                node_sum::<H>(&hash, sibling)
+            };
+        }
+
+        hash == *root
+    }
+
+    /// Compact serialization of the proof, where the empty siblings are omitted:
+    ///
+    /// `has_leaf (1) || [key (32) || value (32)] || len (2) || bitmask (len / 8) || siblings`
+    ///
+    /// The length is encoded as big-endian, and the bit `i` of the bitmask, from the most
+    /// significant bit of the first byte, is set if the sibling `i` is not empty.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let len = self.siblings.len();
+        let mut bytes = Vec::new();
+
+        match self.leaf {
+            Some((key, value)) => {
+                bytes.push(1);
+                bytes.extend_from_slice(key.as_ref());
+                bytes.extend_from_slice(value.as_ref());
+            }
+
+            None => bytes.push(0),
+        }
+
+        bytes.extend_from_slice(&(len as u16).to_be_bytes());
+
+        let mut bitmask = alloc::vec![0u8; len.div_ceil(8)];
+
+        for (i, _) in self
+            .siblings
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s != PLACEHOLDER)
+        {
+            bitmask[i / 8] |= 0x80 >> (i % 8);
+        }
+
+        bytes.extend_from_slice(&bitmask);
+
+        self.siblings
+            .iter()
+            .filter(|s| **s != PLACEHOLDER)
+            .for_each(|s| bytes.extend_from_slice(s.as_ref()));
+
+        bytes
+    }
+
+    /// Parse the compact serialization of [`SparseMerkleProof::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (leaf, bytes) = match bytes.split_first() {
+            Some((0, bytes)) => (None, bytes),
+
+            Some((1, bytes)) if bytes.len() >= 2 * Bytes32::LEN => {
+                let (leaf, bytes) = bytes.split_at(2 * Bytes32::LEN);
+
+                // Safety: the length is checked above
+                let leaf = unsafe {
+                    (
+                        Bytes32::from_slice_unchecked(&leaf[..Bytes32::LEN]),
+                        Bytes32::from_slice_unchecked(&leaf[Bytes32::LEN..]),
+                    )
+                };
+
+                (Some(leaf), bytes)
+            }
+
+            _ => return Err(Error::InvalidMerkleProof),
+        };
+
+        if bytes.len() < 2 {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let (len, bytes) = bytes.split_at(2);
+        let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+
+        if len > DEPTH || bytes.len() < len.div_ceil(8) {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let (bitmask, mut bytes) = bytes.split_at(len.div_ceil(8));
+
+        // The padding of the bitmask must be empty
+        if !len.is_multiple_of(8) && bitmask[len / 8] << (len % 8) != 0 {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let mut siblings = Vec::with_capacity(len);
+
+        for i in 0..len {
+            if bitmask[i / 8] & (0x80 >> (i % 8)) == 0 {
+                siblings.push(PLACEHOLDER);
+                continue;
+            }
+
+            if bytes.len() < Bytes32::LEN {
+                return Err(Error::InvalidMerkleProof);
+            }
+
+            let (sibling, rest) = bytes.split_at(Bytes32::LEN);
+
+            // Safety: the length is checked above
+            let sibling = unsafe { Bytes32::from_slice_unchecked(sibling) };
+
+            // The empty siblings must be omitted
+            if sibling == PLACEHOLDER {
+                return Err(Error::InvalidMerkleProof);
+            }
+
+            siblings.push(sibling);
+            bytes = rest;
+        }
+
+        if !bytes.is_empty() {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        Ok(Self { siblings, leaf })
+    }
+}
+
+/// Digest of a leaf: `H(0x00 || key || value)`
+fn leaf_sum<H>(key: &Bytes32, value: &Bytes32) -> Bytes32
+where
+    H: CryptoHasher,
+{
+    H::default()
+        .chain([LEAF_PREFIX])
+        .chain(key)
+        .chain(value)
+        .finalize()
+}
+
+// Bit of the key at `depth`, from the most significant bit of the first byte
+fn bit(key: &Bytes32, depth: usize) -> bool {
+    key[depth / 8] & (0x80 >> (depth % 8)) != 0
+}
@@ -1,5 +1,9 @@
+use crate::hasher::{CryptoHasher, DigestHasher};
 use crate::Hasher;
 
+use sha2::digest::consts::U32;
+use sha2::digest::{Digest, FixedOutputReset};
+
 pub use fuel_types::Bytes32;
 
 use core::fmt;
@@ -23,6 +27,23 @@ impl Message {
         Self(Hasher::hash(message))
     }
 
+    /// Normalize a message for signature with the provided hash algorithm
+    pub fn new_with<H, M>(message: M) -> Self
+    where
+        H: CryptoHasher,
+        M: AsRef<[u8]>,
+    {
+        Self(H::hash(message))
+    }
+
+    /// Consume a hasher of arbitrary algorithm, returning its digest as message
+    pub fn from_hasher<H>(hasher: H) -> Self
+    where
+        H: CryptoHasher,
+    {
+        Self(hasher.finalize())
+    }
+
     /// Add a conversion from arbitrary slices into owned
     ///
     /// # Safety
@@ -83,16 +104,22 @@ impl From<Message> for [u8; Message::LEN] {
     }
 }
 
-impl From<&Hasher> for Message {
-    fn from(hasher: &Hasher) -> Self {
-        // Safety: `Hasher` is a cryptographic hash
+impl<D> From<&DigestHasher<D>> for Message
+where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
+{
+    fn from(hasher: &DigestHasher<D>) -> Self {
+        // Safety: `DigestHasher` is a cryptographic hash
         unsafe { Self::from_bytes_unchecked(*hasher.digest()) }
     }
 }
 
-impl From<Hasher> for Message {
-    fn from(hasher: Hasher) -> Self {
-        // Safety: `Hasher` is a cryptographic hash
+impl<D> From<DigestHasher<D>> for Message
+where
+    D: Digest<OutputSize = U32> + FixedOutputReset + Default + Clone,
+{
+    fn from(hasher: DigestHasher<D>) -> Self {
+        // Safety: `DigestHasher` is a cryptographic hash
         unsafe { Self::from_bytes_unchecked(*hasher.finalize()) }
     }
 }
@@ -138,3 +165,18 @@ mod use_std {
         }
     }
 }
+
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use crate::Message;
+
+    use k256::FieldBytes;
+
+    impl Message {
+        pub(crate) fn to_k256(&self) -> &FieldBytes {
+            // The message is a 32-byte digest, so it maps directly into the field bytes
+            // representation used by the prehash APIs of `k256`
+            FieldBytes::from_slice(self.as_ref())
+        }
+    }
+}
@@ -5,13 +5,175 @@ pub struct FuelMnemonic;
 mod use_std {
     use super::FuelMnemonic;
     use crate::Error;
-    use coins_bip39::{English, Mnemonic};
+    use coins_bip39::{
+        ChineseSimplified, ChineseTraditional, Czech, English, French, Italian, Japanese, Korean,
+        Mnemonic, Portuguese, Spanish, Wordlist,
+    };
+    use sha2::{Digest, Sha256};
+    use unicode_normalization::UnicodeNormalization;
+    use zeroize::Zeroize;
 
     pub type W = English;
 
     #[cfg(feature = "random")]
     use rand::Rng;
 
+    /// Wordlist of a BIP-39 mnemonic phrase
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum MnemonicLanguage {
+        /// English wordlist
+        #[default]
+        English,
+
+        /// Chinese (simplified) wordlist
+        ChineseSimplified,
+
+        /// Chinese (traditional) wordlist
+        ChineseTraditional,
+
+        /// Czech wordlist
+        Czech,
+
+        /// French wordlist
+        French,
+
+        /// Italian wordlist
+        Italian,
+
+        /// Japanese wordlist. The words are separated by ideographic spaces.
+        Japanese,
+
+        /// Korean wordlist
+        Korean,
+
+        /// Portuguese wordlist
+        Portuguese,
+
+        /// Spanish wordlist
+        Spanish,
+    }
+
+    // Call a function generic over the wordlist of the language
+    macro_rules! with_wordlist {
+        ($language:expr, $f:ident($($arg:expr),*)) => {
+            match $language {
+                MnemonicLanguage::English => $f::<English>($($arg),*),
+                MnemonicLanguage::ChineseSimplified => $f::<ChineseSimplified>($($arg),*),
+                MnemonicLanguage::ChineseTraditional => $f::<ChineseTraditional>($($arg),*),
+                MnemonicLanguage::Czech => $f::<Czech>($($arg),*),
+                MnemonicLanguage::French => $f::<French>($($arg),*),
+                MnemonicLanguage::Italian => $f::<Italian>($($arg),*),
+                MnemonicLanguage::Japanese => $f::<Japanese>($($arg),*),
+                MnemonicLanguage::Korean => $f::<Korean>($($arg),*),
+                MnemonicLanguage::Portuguese => $f::<Portuguese>($($arg),*),
+                MnemonicLanguage::Spanish => $f::<Spanish>($($arg),*),
+            }
+        };
+    }
+
+    fn wordlist<L: Wordlist>() -> &'static [&'static str] {
+        L::get_all()
+    }
+
+    fn seed<L: Wordlist>(phrase: &str, passphrase: &str) -> Result<[u8; 64], Error> {
+        Ok(Mnemonic::<L>::new_from_phrase(phrase)?.to_seed(Some(passphrase))?)
+    }
+
+    #[cfg(feature = "random")]
+    fn generate<L: Wordlist>(rng: &mut impl Rng, count: usize) -> Result<String, Error> {
+        Ok(Mnemonic::<L>::new_with_count(rng, count)?.to_phrase())
+    }
+
+    impl MnemonicLanguage {
+        /// All the supported languages, ordered by precedence of the language detection
+        pub const ALL: [Self; 10] = [
+            Self::English,
+            Self::ChineseSimplified,
+            Self::ChineseTraditional,
+            Self::Czech,
+            Self::French,
+            Self::Italian,
+            Self::Japanese,
+            Self::Korean,
+            Self::Portuguese,
+            Self::Spanish,
+        ];
+
+        /// The 2048 words of the language, in NFKD form
+        pub fn words(&self) -> &'static [&'static str] {
+            with_wordlist!(self, wordlist())
+        }
+
+        const fn separator(&self) -> &'static str {
+            match self {
+                Self::Japanese => "\u{3000}",
+                _ => " ",
+            }
+        }
+
+        // Wordlist indices of the words of a phrase, with a verified checksum. The phrase is
+        // normalized, so the words can be provided in any Unicode form.
+        fn indices(&self, phrase: &str) -> Result<Vec<usize>, Error> {
+            let phrase: String = phrase.nfkd().collect();
+            let words = self.words();
+            let count = phrase.split_whitespace().count();
+
+            if !matches!(count, 12 | 15 | 18 | 21 | 24) {
+                return Err(Error::InvalidMnemonic);
+            }
+
+            let indices = phrase
+                .split_whitespace()
+                .enumerate()
+                .map(|(i, word)| {
+                    words
+                        .iter()
+                        .position(|w| *w == word)
+                        .ok_or(Error::InvalidMnemonicWord(i))
+                })
+                .collect::<Result<Vec<usize>, Error>>()?;
+
+            // Every word encodes 11 bits; the last `count / 3` bits are the checksum
+            let mut bits = vec![0u8; (count * 11).div_ceil(8)];
+
+            for (i, index) in indices.iter().enumerate() {
+                for b in 0..11 {
+                    if (index >> (10 - b)) & 1 == 1 {
+                        let position = i * 11 + b;
+
+                        bits[position / 8] |= 0x80 >> (position % 8);
+                    }
+                }
+            }
+
+            let length = count * 4 / 3;
+            let shift = 8 - count / 3;
+            let checksum = Sha256::digest(&bits[..length])[0] >> shift;
+            let valid = checksum == bits[length] >> shift;
+
+            bits.zeroize();
+
+            if valid {
+                Ok(indices)
+            } else {
+                Err(Error::InvalidMnemonicChecksum)
+            }
+        }
+
+        // Phrase with the canonical words, separated by single spaces
+        fn canonical(&self, phrase: &str) -> Result<String, Error> {
+            let words = self.words();
+            let phrase = self
+                .indices(phrase)?
+                .into_iter()
+                .map(|index| words[index])
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            Ok(phrase)
+        }
+    }
+
     impl FuelMnemonic {
         /// Generates a random mnemonic phrase given a random number generator and
         /// the number of words to generate, `count`.
@@ -20,7 +182,94 @@ mod use_std {
             rng: &mut R,
             count: usize,
         ) -> Result<String, Error> {
-            Ok(Mnemonic::<W>::new_with_count(rng, count)?.to_phrase()?)
+            Ok(Mnemonic::<W>::new_with_count(rng, count)?.to_phrase())
+        }
+
+        /// Generates a random mnemonic phrase of `count` words, from the wordlist of the
+        /// provided language.
+        #[cfg(feature = "random")]
+        pub fn generate_mnemonic_phrase_in<R: Rng>(
+            rng: &mut R,
+            count: usize,
+            language: MnemonicLanguage,
+        ) -> Result<String, Error> {
+            let phrase = with_wordlist!(language, generate(rng, count))?;
+
+            Ok(phrase.replace(' ', language.separator()))
+        }
+
+        /// Validate a mnemonic phrase, returning its detected language.
+        ///
+        /// The languages are tried in the order of [`MnemonicLanguage::ALL`]; a phrase valid
+        /// in several languages is attributed to the first one. Fails with
+        /// [`Error::InvalidMnemonic`] if the number of words isn't supported, and otherwise
+        /// with the error of the language that matches the phrase the best:
+        /// [`Error::InvalidMnemonicChecksum`] if all the words are in its wordlist, and
+        /// [`Error::InvalidMnemonicWord`] with the position of the first unknown word.
+        pub fn validate(phrase: &str) -> Result<MnemonicLanguage, Error> {
+            let mut best = Error::InvalidMnemonic;
+
+            for language in MnemonicLanguage::ALL {
+                match language.indices(phrase) {
+                    Ok(_) => return Ok(language),
+                    Err(Error::InvalidMnemonic) => return Err(Error::InvalidMnemonic),
+
+                    Err(e) => {
+                        best = match (best, e) {
+                            (Error::InvalidMnemonicWord(i), Error::InvalidMnemonicWord(j))
+                                if j <= i =>
+                            {
+                                best
+                            }
+
+                            (Error::InvalidMnemonicChecksum, _) => best,
+                            _ => e,
+                        }
+                    }
+                }
+            }
+
+            Err(best)
+        }
+
+        /// Validate a mnemonic phrase in the provided language.
+        ///
+        /// Fails with [`Error::InvalidMnemonicWord`] with the position of the first word that
+        /// isn't in the wordlist, and with [`Error::InvalidMnemonicChecksum`] if the checksum
+        /// doesn't match.
+        pub fn validate_in(phrase: &str, language: MnemonicLanguage) -> Result<(), Error> {
+            language.indices(phrase).map(|_| ())
+        }
+
+        /// BIP-39 seed of a mnemonic phrase in any supported language, protected by a
+        /// passphrase; an empty passphrase is the same as no passphrase.
+        ///
+        /// Both the phrase and the passphrase are normalized to the NFKD form.
+        pub fn to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64], Error> {
+            let language = Self::validate(phrase)?;
+
+            Self::to_seed_in(phrase, passphrase, language)
+        }
+
+        /// BIP-39 seed of a mnemonic phrase in the provided language, protected by a
+        /// passphrase.
+        pub fn to_seed_in(
+            phrase: &str,
+            passphrase: &str,
+            language: MnemonicLanguage,
+        ) -> Result<[u8; 64], Error> {
+            let mut phrase = language.canonical(phrase)?;
+            let mut passphrase: String = passphrase.nfkd().collect();
+
+            let seed = with_wordlist!(language, seed(&phrase, &passphrase));
+
+            phrase.zeroize();
+            passphrase.zeroize();
+
+            seed
         }
     }
 }
+
+#[cfg(feature = "std")]
+pub use use_std::MnemonicLanguage;
@@ -0,0 +1,65 @@
+use crate::keystore::{Seal, SealedKeystore};
+use crate::{AsyncSigner, Error, Message, PublicKey, Signature};
+
+use core::fmt;
+
+/// Signer that never exposes the secret keys of its keystore.
+///
+/// The keystore is moved into the signer and can't be accessed anymore: the secrets are read
+/// only to produce a signature, and the copies are zeroed right after. The handles can be passed
+/// to untrusted code, as plugins, without leaking the keys; a keystore behind an `Arc` is shared
+/// between the clones of a handle.
+#[derive(Clone)]
+pub struct OpaqueSigner<K> {
+    keystore: K,
+}
+
+impl<K> OpaqueSigner<K>
+where
+    K: SealedKeystore,
+{
+    /// Seal the provided keystore
+    pub const fn new(keystore: K) -> Self {
+        Self { keystore }
+    }
+
+    /// Public key indexed by `id`.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the keystore doesn't contain the key.
+    pub fn public(&self, id: &K::KeyId) -> Result<PublicKey, K::Error> {
+        let public = self.keystore.sealed_public(id)?.ok_or(Error::KeyNotFound)?;
+
+        Ok(public.into_owned())
+    }
+
+    /// Sign a given message with the secret key identified by `id`.
+    ///
+    /// Fails with [`Error::KeyNotFound`] if the keystore doesn't contain the key.
+    pub fn sign(&self, id: &K::KeyId, message: &Message) -> Result<Signature, K::Error> {
+        self.keystore
+            .with_secret(id, Seal::new(), |secret| Signature::sign(secret, message))
+    }
+}
+
+impl<K> fmt::Debug for OpaqueSigner<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpaqueSigner").finish_non_exhaustive()
+    }
+}
+
+impl<K> AsyncSigner for OpaqueSigner<K>
+where
+    K: SealedKeystore + Sync,
+    K::KeyId: Sync,
+{
+    type Error = K::Error;
+    type KeyId = K::KeyId;
+
+    async fn public(&self, id: &K::KeyId) -> Result<PublicKey, K::Error> {
+        OpaqueSigner::public(self, id)
+    }
+
+    async fn sign(&self, id: &K::KeyId, message: &Message) -> Result<Signature, K::Error> {
+        OpaqueSigner::sign(self, id, message)
+    }
+}
@@ -112,15 +112,71 @@ impl fmt::Display for PublicKey {
     }
 }
 
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
+    use super::*;
+    use crate::{CompressedPublicKey, Error};
+
+    use core::str;
+
+    impl PublicKey {
+        /// Check if the secret key representation is in the curve.
+        pub fn is_in_curve(&self) -> bool {
+            // Safety: struct is guaranteed to reference itself with correct len
+            unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
+        }
+
+        /// Decode a public key from a SEC1 encoded point, either compressed or uncompressed.
+        pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+            match bytes {
+                [4, public @ ..] if public.len() == Self::LEN => PublicKey::try_from(public),
+                _ => CompressedPublicKey::try_from(bytes).and_then(|pk| pk.decompress()),
+            }
+        }
+    }
+
+    impl TryFrom<Bytes64> for PublicKey {
+        type Error = Error;
+
+        fn try_from(b: Bytes64) -> Result<Self, Self::Error> {
+            let public = PublicKey(b);
+
+            public
+                .is_in_curve()
+                .then_some(public)
+                .ok_or(Error::InvalidPublicKey)
+        }
+    }
+
+    impl TryFrom<&[u8]> for PublicKey {
+        type Error = Error;
+
+        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+            Bytes64::try_from(slice)
+                .map_err(|_| Error::InvalidPublicKey)
+                .and_then(PublicKey::try_from)
+        }
+    }
+
+    impl str::FromStr for PublicKey {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Bytes64::from_str(s)
+                .map_err(|_| Error::InvalidPublicKey)
+                .and_then(PublicKey::try_from)
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 mod use_std {
     use super::*;
     use crate::{Error, SecretKey};
 
-    use secp256k1::{Error as Secp256k1Error, PublicKey as Secp256k1PublicKey, Secp256k1};
+    use secp256k1::{PublicKey as Secp256k1PublicKey, Secp256k1};
 
     use core::borrow::Borrow;
-    use core::str;
 
     const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
 
@@ -142,7 +198,7 @@ mod use_std {
 
             let public = Self::as_ref_unchecked(slice);
 
-            let mut public_with_flag = [0u8; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+            let mut public_with_flag = [SECP_UNCOMPRESSED_FLAG; UNCOMPRESSED_PUBLIC_KEY_SIZE];
 
             public_with_flag[1..].copy_from_slice(public.as_ref());
 
@@ -157,12 +213,6 @@ mod use_std {
             curve == 1
         }
 
-        /// Check if the secret key representation is in the curve.
-        pub fn is_in_curve(&self) -> bool {
-            // Safety: struct is guaranteed to reference itself with correct len
-            unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
-        }
-
         pub(crate) fn from_secp(pk: &Secp256k1PublicKey) -> PublicKey {
             debug_assert_eq!(
                 UNCOMPRESSED_PUBLIC_KEY_SIZE,
@@ -193,29 +243,6 @@ mod use_std {
         }
     }
 
-    impl TryFrom<Bytes64> for PublicKey {
-        type Error = Error;
-
-        fn try_from(b: Bytes64) -> Result<Self, Self::Error> {
-            let public = PublicKey(b);
-
-            public
-                .is_in_curve()
-                .then_some(public)
-                .ok_or(Error::InvalidPublicKey)
-        }
-    }
-
-    impl TryFrom<&[u8]> for PublicKey {
-        type Error = Error;
-
-        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-            Bytes64::try_from(slice)
-                .map_err(|_| Secp256k1Error::InvalidPublicKey.into())
-                .and_then(PublicKey::try_from)
-        }
-    }
-
     impl From<&SecretKey> for PublicKey {
         fn from(s: &SecretKey) -> PublicKey {
             let secp = Secp256k1::new();
@@ -229,14 +256,62 @@ mod use_std {
             Self::from_secp(&public)
         }
     }
+}
 
-    impl str::FromStr for PublicKey {
-        type Err = Error;
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use super::*;
+    use crate::{Error, SecretKey};
 
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-            Bytes64::from_str(s)
-                .map_err(|_| Secp256k1Error::InvalidPublicKey.into())
-                .and_then(PublicKey::try_from)
+    use k256::ecdsa::VerifyingKey;
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+
+    // SEC1 tag for an uncompressed point, consistent with the secp256k1 backend
+    const SECP_UNCOMPRESSED_FLAG: u8 = 4;
+
+    impl PublicKey {
+        /// Check if the provided slice represents a public key that is in the
+        /// curve.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`PublicKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+            let public = Self::as_ref_unchecked(slice);
+
+            public._to_k256().is_ok()
+        }
+
+        pub(crate) fn from_k256(pk: &VerifyingKey) -> PublicKey {
+            let pk = pk.to_encoded_point(false);
+
+            debug_assert_eq!(UNCOMPRESSED_PUBLIC_KEY_SIZE, pk.len());
+            debug_assert_eq!(SECP_UNCOMPRESSED_FLAG, pk.as_bytes()[0]);
+
+            // Ignore the first byte of the compression flag
+            let pk = &pk.as_bytes()[1..];
+
+            // Safety: uncompressed points are always of the expected length
+            unsafe { Self::from_slice_unchecked(pk) }
+        }
+
+        pub(crate) fn _to_k256(&self) -> Result<VerifyingKey, Error> {
+            let mut pk = [SECP_UNCOMPRESSED_FLAG; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+
+            pk[1..].copy_from_slice(self.as_ref());
+
+            VerifyingKey::from_sec1_bytes(&pk).map_err(|_| Error::InvalidPublicKey)
+        }
+    }
+
+    impl From<&SecretKey> for PublicKey {
+        fn from(s: &SecretKey) -> PublicKey {
+            let secret = s.to_k256();
+
+            Self::from_k256(&secret.verifying_key())
         }
     }
 }
@@ -0,0 +1,431 @@
+use crate::{Error, PublicKey};
+
+use fuel_types::{Bytes32, Bytes64};
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+/// Secp256k1 x-only public key, as defined in BIP-340
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct XOnlyPublicKey(Bytes32);
+
+impl XOnlyPublicKey {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes32::LEN;
+
+    /// Copy-free reference cast
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will save unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will fit the curve. The curve
+    /// security can be checked with [`XOnlyPublicKey::is_in_curve`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes32::from_slice_unchecked(bytes))
+    }
+}
+
+impl Deref for XOnlyPublicKey {
+    type Target = [u8; XOnlyPublicKey::LEN];
+
+    fn deref(&self) -> &[u8; XOnlyPublicKey::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for XOnlyPublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for XOnlyPublicKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<XOnlyPublicKey> for [u8; XOnlyPublicKey::LEN] {
+    fn from(pk: XOnlyPublicKey) -> [u8; XOnlyPublicKey::LEN] {
+        pk.0.into()
+    }
+}
+
+impl From<&PublicKey> for XOnlyPublicKey {
+    fn from(pk: &PublicKey) -> XOnlyPublicKey {
+        // The public key is the concatenation of the `x` and `y` coordinates; the parity of
+        // `y` is implicitly even for x-only keys.
+        //
+        // Safety: the public key is larger than the x-only key
+        unsafe { Self::from_slice_unchecked(pk.as_ref()) }
+    }
+}
+
+impl fmt::LowerHex for XOnlyPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for XOnlyPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for XOnlyPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for XOnlyPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Secp256k1 Schnorr signature, as defined in BIP-340
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SchnorrSignature(Bytes64);
+
+impl SchnorrSignature {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes64::LEN;
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes64::from_slice_unchecked(bytes))
+    }
+
+    /// Copy-free reference cast
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    ///
+    /// # Safety
+    ///
+    /// Inputs smaller than `Self::LEN` will cause undefined behavior.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will avoid unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+}
+
+impl Deref for SchnorrSignature {
+    type Target = [u8; SchnorrSignature::LEN];
+
+    fn deref(&self) -> &[u8; SchnorrSignature::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for SchnorrSignature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for SchnorrSignature {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<SchnorrSignature> for [u8; SchnorrSignature::LEN] {
+    fn from(signature: SchnorrSignature) -> [u8; SchnorrSignature::LEN] {
+        signature.0.into()
+    }
+}
+
+impl fmt::LowerHex for SchnorrSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for SchnorrSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for SchnorrSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for SchnorrSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Bytes64> for SchnorrSignature {
+    fn from(b: Bytes64) -> Self {
+        Self(b)
+    }
+}
+
+impl From<SchnorrSignature> for Bytes64 {
+    fn from(s: SchnorrSignature) -> Self {
+        s.0
+    }
+}
+
+impl str::FromStr for SchnorrSignature {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| s.into())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
+    use super::*;
+    use crate::{Message, SecretKey};
+
+    impl XOnlyPublicKey {
+        /// Check if the x-only key representation is in the curve.
+        pub fn is_in_curve(&self) -> bool {
+            // Safety: struct is guaranteed to reference itself with correct len
+            unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
+        }
+    }
+
+    impl From<&SecretKey> for XOnlyPublicKey {
+        fn from(s: &SecretKey) -> XOnlyPublicKey {
+            Self::from(&s.public_key())
+        }
+    }
+
+    impl TryFrom<Bytes32> for XOnlyPublicKey {
+        type Error = Error;
+
+        fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+            let public = XOnlyPublicKey(b);
+
+            public
+                .is_in_curve()
+                .then_some(public)
+                .ok_or(Error::InvalidPublicKey)
+        }
+    }
+
+    impl TryFrom<&[u8]> for XOnlyPublicKey {
+        type Error = Error;
+
+        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+            Bytes32::try_from(slice)
+                .map_err(|_| Error::InvalidPublicKey)
+                .and_then(XOnlyPublicKey::try_from)
+        }
+    }
+
+    impl str::FromStr for XOnlyPublicKey {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Bytes32::from_str(s)
+                .map_err(|_| Error::InvalidPublicKey)
+                .and_then(XOnlyPublicKey::try_from)
+        }
+    }
+
+    impl SchnorrSignature {
+        /// Sign a given message without auxiliary randomness
+        ///
+        /// This is equivalent to [`SchnorrSignature::sign_with_aux_rand`] with zeroed auxiliary
+        /// data, and yields a deterministic signature.
+        pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+            Self::sign_with_aux_rand(secret, message, &[0u8; 32])
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod use_std {
+    use super::*;
+    use crate::{Message, SecretKey};
+
+    use lazy_static::lazy_static;
+    use secp256k1::{
+        schnorr::Signature as Secp256k1SchnorrSignature, KeyPair, Secp256k1,
+        XOnlyPublicKey as Secp256k1XOnlyPublicKey,
+    };
+
+    use std::borrow::Borrow;
+
+    lazy_static! {
+        static ref SIGNING_SECP: Secp256k1<secp256k1::SignOnly> = Secp256k1::signing_only();
+        static ref VERIFY_SECP: Secp256k1<secp256k1::VerifyOnly> = Secp256k1::verification_only();
+    }
+
+    impl XOnlyPublicKey {
+        /// Check if the provided slice represents an x-only key that is in the
+        /// curve.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`XOnlyPublicKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+            Self::as_ref_unchecked(slice)._to_secp().is_ok()
+        }
+
+        pub(crate) fn _to_secp(&self) -> Result<Secp256k1XOnlyPublicKey, Error> {
+            let pk = Secp256k1XOnlyPublicKey::from_slice(self.as_ref())?;
+
+            Ok(pk)
+        }
+    }
+
+    impl SchnorrSignature {
+        /// Sign a given message with the provided auxiliary randomness
+        ///
+        /// The signature scheme is described in
+        /// <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+        pub fn sign_with_aux_rand(
+            secret: &SecretKey,
+            message: &Message,
+            aux_rand: &[u8; 32],
+        ) -> Self {
+            let keypair = KeyPair::from_secret_key(&SIGNING_SECP, secret.borrow());
+            let message = message.to_secp();
+
+            let signature = SIGNING_SECP.sign_schnorr_with_aux_rand(&message, &keypair, aux_rand);
+
+            // Safety: the security of this call reflects the security of secp256k1 FFI
+            unsafe { Self::from_slice_unchecked(signature.as_ref()) }
+        }
+
+        /// Verify a signature produced by [`SchnorrSignature::sign`]
+        pub fn verify(&self, pk: &XOnlyPublicKey, message: &Message) -> Result<(), Error> {
+            let signature = Secp256k1SchnorrSignature::from_slice(self.as_ref())?;
+            let message = message.to_secp();
+            let pk = pk._to_secp()?;
+
+            VERIFY_SECP.verify_schnorr(&signature, &message, &pk)?;
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use super::*;
+    use crate::{Message, SecretKey};
+
+    use k256::schnorr::{
+        Signature as K256SchnorrSignature, SigningKey as K256SchnorrSigningKey,
+        VerifyingKey as K256SchnorrVerifyingKey,
+    };
+
+    impl XOnlyPublicKey {
+        /// Check if the provided slice represents an x-only key that is in the
+        /// curve.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`XOnlyPublicKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+            Self::as_ref_unchecked(slice)._to_k256().is_ok()
+        }
+
+        pub(crate) fn _to_k256(&self) -> Result<K256SchnorrVerifyingKey, Error> {
+            K256SchnorrVerifyingKey::from_bytes(self.as_ref()).map_err(|_| Error::InvalidPublicKey)
+        }
+    }
+
+    impl SchnorrSignature {
+        /// Sign a given message with the provided auxiliary randomness
+        ///
+        /// The signature scheme is described in
+        /// <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+        pub fn sign_with_aux_rand(
+            secret: &SecretKey,
+            message: &Message,
+            aux_rand: &[u8; 32],
+        ) -> Self {
+            let secret = K256SchnorrSigningKey::from_bytes(secret.as_ref())
+                .expect("Secret key is not in the field");
+
+            let signature = secret
+                .try_sign_prehashed(message, aux_rand)
+                .expect("Signing with a field-checked secret is infallible");
+
+            // Safety: the security of this call reflects the security of `k256`
+            unsafe { Self::from_slice_unchecked(signature.as_ref()) }
+        }
+
+        /// Verify a signature produced by [`SchnorrSignature::sign`]
+        pub fn verify(&self, pk: &XOnlyPublicKey, message: &Message) -> Result<(), Error> {
+            let signature = K256SchnorrSignature::try_from(self.as_ref())
+                .map_err(|_| Error::InvalidSignature)?;
+            let pk = pk._to_k256()?;
+
+            pk.verify_prehashed(message, &signature)
+                .map_err(|_| Error::InvalidSignature)
+        }
+    }
+}
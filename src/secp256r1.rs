@@ -0,0 +1,15 @@
+//! Secp256r1 (NIST P-256) primitives, as used by passkeys and WebAuthn authenticators.
+//!
+//! The types follow the conventions of their secp256k1 counterparts exported from the crate
+//! root, including the recovery id compressed into the signature.
+
+mod public;
+mod secret;
+mod signature;
+
+#[cfg(feature = "webauthn")]
+pub mod webauthn;
+
+pub use public::PublicKey;
+pub use secret::SecretKey;
+pub use signature::Signature;
@@ -0,0 +1,199 @@
+use crate::secp256r1::SecretKey;
+use crate::{Error, Hasher};
+
+use fuel_types::{Bytes32, Bytes64};
+use p256::ecdsa::VerifyingKey;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+
+// SEC1 tag for an uncompressed point
+const SEC1_UNCOMPRESSED_FLAG: u8 = 4;
+
+/// Secp256r1 public key
+///
+/// Represented as the uncompressed `x || y` coordinates, without the SEC1 tag.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct PublicKey(Bytes64);
+
+impl PublicKey {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes64::LEN;
+
+    /// Copy-free reference cast
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will save unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will fit the curve. The curve
+    /// security can be checked with [`PublicKey::is_in_curve`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the curve.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes64::from_slice_unchecked(bytes))
+    }
+
+    /// Check if the provided slice represents a public key that is in the
+    /// curve.
+    ///
+    /// # Safety
+    ///
+    /// This function extends the unsafety of
+    /// [`PublicKey::as_ref_unchecked`].
+    pub unsafe fn is_slice_in_curve_unchecked(slice: &[u8]) -> bool {
+        Self::as_ref_unchecked(slice).to_p256().is_ok()
+    }
+
+    /// Check if the public key representation is in the curve.
+    pub fn is_in_curve(&self) -> bool {
+        // Safety: struct is guaranteed to reference itself with correct len
+        unsafe { Self::is_slice_in_curve_unchecked(self.as_ref()) }
+    }
+
+    /// Hash of the public key
+    pub fn hash(&self) -> Bytes32 {
+        Hasher::hash(self.as_ref())
+    }
+
+    pub(crate) fn from_p256(pk: &VerifyingKey) -> PublicKey {
+        let pk = pk.to_encoded_point(false);
+
+        debug_assert_eq!(UNCOMPRESSED_PUBLIC_KEY_SIZE, pk.len());
+        debug_assert_eq!(SEC1_UNCOMPRESSED_FLAG, pk.as_bytes()[0]);
+
+        // Ignore the first byte of the compression flag
+        let pk = &pk.as_bytes()[1..];
+
+        // Safety: uncompressed points are always of the expected length
+        unsafe { Self::from_slice_unchecked(pk) }
+    }
+
+    pub(crate) fn to_p256(self) -> Result<VerifyingKey, Error> {
+        let mut pk = [SEC1_UNCOMPRESSED_FLAG; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+
+        pk[1..].copy_from_slice(self.as_ref());
+
+        VerifyingKey::from_sec1_bytes(&pk).map_err(|_| Error::InvalidPublicKey)
+    }
+}
+
+impl Deref for PublicKey {
+    type Target = [u8; PublicKey::LEN];
+
+    fn deref(&self) -> &[u8; PublicKey::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for PublicKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<PublicKey> for [u8; PublicKey::LEN] {
+    fn from(pk: PublicKey) -> [u8; PublicKey::LEN] {
+        pk.0.into()
+    }
+}
+
+impl From<&SecretKey> for PublicKey {
+    fn from(s: &SecretKey) -> PublicKey {
+        PublicKey::from_p256(s.to_p256().verifying_key())
+    }
+}
+
+impl TryFrom<Bytes64> for PublicKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes64) -> Result<Self, Self::Error> {
+        let public = PublicKey(b);
+
+        public
+            .is_in_curve()
+            .then_some(public)
+            .ok_or(Error::InvalidPublicKey)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes64::try_from(slice)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl str::FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl fmt::LowerHex for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
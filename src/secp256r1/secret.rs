@@ -0,0 +1,207 @@
+use crate::secp256r1::PublicKey;
+use crate::Error;
+
+use fuel_types::Bytes32;
+use p256::ecdsa::SigningKey;
+use p256::FieldBytes;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+use zeroize::Zeroize;
+
+/// Secp256r1 secret key
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SecretKey(Bytes32);
+
+impl SecretKey {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes32::LEN;
+
+    /// Create a new random secret
+    #[cfg(feature = "random")]
+    pub fn random<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let mut secret = Bytes32::zeroed();
+
+        loop {
+            rng.fill(secret.as_mut());
+
+            // Safety: `Bytes32` is guaranteed to have `Self::LEN` bytes
+            if unsafe { Self::is_slice_in_field_unchecked(secret.as_ref()) } {
+                break;
+            }
+        }
+
+        Self(secret)
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will fit the field. The field
+    /// security can be checked with [`SecretKey::is_in_field`].
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will fit the field.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes32::from_slice_unchecked(bytes))
+    }
+
+    /// Copy-free reference cast
+    ///
+    /// There is no guarantee the provided bytes will fit the field.
+    ///
+    /// # Safety
+    ///
+    /// Inputs smaller than `Self::LEN` will cause undefined behavior.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will avoid unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Check if the provided slice represents a scalar that fits the field.
+    ///
+    /// # Safety
+    ///
+    /// This function extends the unsafety of
+    /// [`SecretKey::as_ref_unchecked`].
+    pub unsafe fn is_slice_in_field_unchecked(slice: &[u8]) -> bool {
+        let secret = Self::as_ref_unchecked(slice);
+
+        // Rejects both the zero scalar and scalars that overflow the curve order
+        SigningKey::from_bytes(FieldBytes::from_slice(secret.as_ref())).is_ok()
+    }
+
+    /// Check if the secret key representation fits the scalar field.
+    pub fn is_in_field(&self) -> bool {
+        // Safety: struct is guaranteed to reference itself with correct len
+        unsafe { Self::is_slice_in_field_unchecked(self.as_ref()) }
+    }
+
+    /// Return the curve representation of this secret.
+    ///
+    /// The discrete logarithm property guarantees this is a one-way
+    /// function.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+
+    // Internal API - the caller is expected to hold a field-checked secret
+    pub(crate) fn to_p256(self) -> SigningKey {
+        SigningKey::from_bytes(FieldBytes::from_slice(self.as_ref()))
+            .expect("Secret key is not in the field")
+    }
+}
+
+impl Deref for SecretKey {
+    type Target = [u8; SecretKey::LEN];
+
+    fn deref(&self) -> &[u8; SecretKey::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for SecretKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<SecretKey> for [u8; SecretKey::LEN] {
+    fn from(secret: SecretKey) -> [u8; SecretKey::LEN] {
+        secret.0.into()
+    }
+}
+
+impl TryFrom<Bytes32> for SecretKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+        let secret = SecretKey(b);
+
+        secret
+            .is_in_field()
+            .then_some(secret)
+            .ok_or(Error::InvalidSecretKey)
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidSecretKey)
+            .and_then(SecretKey::try_from)
+    }
+}
+
+impl str::FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidSecretKey)
+            .and_then(SecretKey::try_from)
+    }
+}
+
+impl fmt::LowerHex for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "random")]
+impl rand::Fill for SecretKey {
+    fn try_fill<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), rand::Error> {
+        *self = Self::random(rng);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "random")]
+impl rand::distributions::Distribution<SecretKey> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SecretKey {
+        SecretKey::random(rng)
+    }
+}
@@ -0,0 +1,249 @@
+use crate::secp256r1::{PublicKey, SecretKey};
+use crate::{Error, Message};
+
+use ecdsa::hazmat::SignPrimitive;
+use ecdsa::RecoveryId;
+use fuel_types::Bytes64;
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use p256::ecdsa::{Signature as P256Signature, VerifyingKey};
+use p256::FieldBytes;
+use sha2::Sha256;
+
+use core::ops::Deref;
+use core::{fmt, str};
+
+/// Secp256r1 signature implementation
+///
+/// The recovery id is compressed into the most significant bit of `s`, with the same scheme as
+/// [`crate::Signature`].
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Signature(Bytes64);
+
+impl Signature {
+    /// Memory length of the type
+    pub const LEN: usize = Bytes64::LEN;
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Add a conversion from arbitrary slices into owned
+    ///
+    /// # Safety
+    ///
+    /// This function will not panic if the length of the slice is smaller than
+    /// `Self::LEN`. Instead, it will cause undefined behavior and read random
+    /// disowned bytes.
+    ///
+    /// There is no guarantee the provided bytes will be a valid signature.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(Bytes64::from_slice_unchecked(bytes))
+    }
+
+    /// Copy-free reference cast
+    ///
+    /// There is no guarantee the provided bytes will fit the field.
+    ///
+    /// # Safety
+    ///
+    /// Inputs smaller than `Self::LEN` will cause undefined behavior.
+    pub unsafe fn as_ref_unchecked(bytes: &[u8]) -> &Self {
+        // The interpreter will frequently make references to keys and values using
+        // logically checked slices.
+        //
+        // This function will avoid unnecessary copy to owned slices for the interpreter
+        // access
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Truncate the recovery id from the signature, producing a valid `r || s`
+    /// representation.
+    pub(crate) fn truncate_recovery_id(&mut self) {
+        self.as_mut()[32] &= 0x7f;
+    }
+
+    // Internal API - this isn't meant to be made public because some assumptions and pre-checks
+    // are performed prior to this call
+    pub(crate) fn to_p256(&mut self) -> Result<(P256Signature, RecoveryId), Error> {
+        let is_y_odd = (self.as_mut()[32] >> 7) == 1;
+
+        self.truncate_recovery_id();
+
+        let signature =
+            P256Signature::from_slice(self.as_ref()).map_err(|_| Error::InvalidSignature)?;
+
+        // The recovery id only takes the most significant bit of `s`, so the upper-S values below
+        // 2^255 are still representable and would make the signature malleable
+        if signature.normalize_s().is_some() {
+            return Err(Error::NonCanonicalSignature);
+        }
+
+        // `Signature::sign` never produces a reduced `x` coordinate
+        Ok((signature, RecoveryId::new(is_y_odd, false)))
+    }
+
+    pub(crate) fn from_p256(signature: P256Signature, v: RecoveryId) -> Self {
+        debug_assert!(!v.is_x_reduced());
+
+        // Safety: the fixed-size representation is `r || s`
+        let mut signature = unsafe { Signature::from_slice_unchecked(&signature.to_bytes()) };
+
+        signature.as_mut()[32] |= (v.is_y_odd() as u8) << 7;
+
+        signature
+    }
+
+    /// Sign a given message and compress the `v` to the signature
+    ///
+    /// The signature is normalized to lower-S, so the most significant bit of `s` is free to
+    /// encode `v`. The compression scheme is described in
+    /// <https://github.com/FuelLabs/fuel-specs/blob/master/specs/protocol/cryptographic_primitives.md#public-key-cryptography>
+    pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+        let secret = secret.to_p256();
+        let message = FieldBytes::from_slice(message.as_ref());
+
+        // The x-coordinate of the nonce point overflows the curve order with a probability of
+        // about 2^-32, and a single recovery bit can't encode it. The first attempt is the plain
+        // RFC-6979 nonce; the unlikely retries extend it with a counter as additional data.
+        let mut counter = 0u32;
+
+        loop {
+            let ad = counter.to_be_bytes();
+            let ad = if counter == 0 { &[][..] } else { &ad[..] };
+
+            let (signature, v) = secret
+                .as_nonzero_scalar()
+                .try_sign_prehashed_rfc6979::<Sha256>(message, ad)
+                .expect("Signing a 32-byte digest with a field-checked secret is infallible");
+
+            let v = v.expect("The recovery ID is always computed for prime order curves");
+
+            if !v.is_x_reduced() {
+                // Negating `s` mirrors the nonce point, flipping the parity of its `y`
+                let signature = match signature.normalize_s() {
+                    Some(signature) => {
+                        Self::from_p256(signature, RecoveryId::new(!v.is_y_odd(), false))
+                    }
+                    None => Self::from_p256(signature, v),
+                };
+
+                return signature;
+            }
+
+            counter += 1;
+        }
+    }
+
+    /// Recover the public key from a signature performed with
+    /// [`Signature::sign`]
+    ///
+    /// Upper-S signatures fail with [`Error::NonCanonicalSignature`].
+    ///
+    /// It takes the signature as owned because this operation is not idempotent. The taken
+    /// signature will not be recoverable. Signatures are meant to be single use, so this
+    /// avoids unnecessary copy.
+    pub fn recover(mut self, message: &Message) -> Result<PublicKey, Error> {
+        let (signature, v) = self.to_p256()?;
+
+        VerifyingKey::recover_from_prehash(message.as_ref(), &signature, v)
+            .map(|pk| PublicKey::from_p256(&pk))
+            .map_err(|_| Error::InvalidSignature)
+    }
+
+    /// Verify a signature produced by [`Signature::sign`]
+    ///
+    /// The signature is verified natively against the provided public key, so the recovery
+    /// id is not taken into account. Only lower-S signatures, as produced by
+    /// [`Signature::sign`], are accepted; upper-S signatures fail with
+    /// [`Error::NonCanonicalSignature`].
+    ///
+    /// It takes the signature as owned because this operation is not idempotent. The taken
+    /// signature will not be recoverable. Signatures are meant to be single use, so this
+    /// avoids unnecessary copy.
+    pub fn verify(mut self, pk: &PublicKey, message: &Message) -> Result<(), Error> {
+        let (signature, _) = self.to_p256()?;
+        let pk = pk.to_p256()?;
+
+        pk.verify_prehash(message.as_ref(), &signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+impl Deref for Signature {
+    type Target = [u8; Signature::LEN];
+
+    fn deref(&self) -> &[u8; Signature::LEN] {
+        self.0.deref()
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[u8]> for Signature {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl From<Signature> for [u8; Signature::LEN] {
+    fn from(signature: Signature) -> [u8; Signature::LEN] {
+        signature.0.into()
+    }
+}
+
+impl From<Bytes64> for Signature {
+    fn from(b: Bytes64) -> Self {
+        Self(b)
+    }
+}
+
+impl From<Signature> for Bytes64 {
+    fn from(s: Signature) -> Self {
+        s.0
+    }
+}
+
+impl str::FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| s.into())
+    }
+}
+
+impl fmt::LowerHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::UpperHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,251 @@
+//! WebAuthn assertion verification for passkeys backed by secp256r1.
+//!
+//! An assertion is produced by the authenticator over
+//! `authenticatorData || sha256(clientDataJSON)`, where the challenge of the client data is the
+//! [`Message`] being authorized. Check <https://www.w3.org/TR/webauthn-2/#sctn-verifying-assertion>
+
+use crate::secp256r1::{PublicKey, Signature};
+use crate::{Error, Hasher, Message};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::Signature as P256Signature;
+
+/// Length of the fixed prefix of the authenticator data: `rpIdHash || flags || signCount`
+const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+
+/// Offset of the flags byte in the authenticator data
+const FLAGS_OFFSET: usize = 32;
+
+/// User present flag
+const FLAG_UP: u8 = 0x01;
+
+/// User verified flag
+const FLAG_UV: u8 = 0x04;
+
+/// Length of an unpadded base64url encoded [`Message`]
+const CHALLENGE_LEN: usize = 43;
+
+/// Prefix of the client data serialization of an assertion, up to the challenge.
+///
+/// The serialization of the client data is specified so the members `type` and `challenge` are
+/// always emitted first, allowing a verification without a JSON parser. Check
+/// <https://www.w3.org/TR/webauthn-2/#clientdatajson-verification>
+const CLIENT_DATA_PREFIX: &[u8] = br#"{"type":"webauthn.get","challenge":""#;
+
+/// Member of the client data serialization following the challenge
+const CLIENT_DATA_ORIGIN: &[u8] = br#"","origin":""#;
+
+/// Relying party expected by an assertion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelyingParty<'a> {
+    id: &'a str,
+    origin: &'a str,
+}
+
+impl<'a> RelyingParty<'a> {
+    /// Create a new relying party from its id, e.g. `example.com`, and the origin of the
+    /// requesting page, e.g. `https://login.example.com`.
+    pub const fn new(id: &'a str, origin: &'a str) -> Self {
+        Self { id, origin }
+    }
+
+    /// Id of the relying party
+    pub const fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// Origin of the requesting page
+    pub const fn origin(&self) -> &'a str {
+        self.origin
+    }
+}
+
+/// WebAuthn assertion, as returned by the authenticator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Assertion<'a> {
+    authenticator_data: &'a [u8],
+    client_data_json: &'a [u8],
+}
+
+impl<'a> Assertion<'a> {
+    /// Create a new assertion from the `authenticatorData` and `clientDataJSON` fields of the
+    /// authenticator response.
+    pub const fn new(authenticator_data: &'a [u8], client_data_json: &'a [u8]) -> Self {
+        Self {
+            authenticator_data,
+            client_data_json,
+        }
+    }
+
+    /// Authenticator data of the assertion
+    pub const fn authenticator_data(&self) -> &'a [u8] {
+        self.authenticator_data
+    }
+
+    /// Client data of the assertion
+    pub const fn client_data_json(&self) -> &'a [u8] {
+        self.client_data_json
+    }
+
+    /// Message signed by the authenticator: `sha256(authenticatorData || sha256(clientDataJSON))`
+    pub fn message(&self) -> Message {
+        Hasher::default()
+            .chain(self.authenticator_data)
+            .chain(Hasher::hash(self.client_data_json))
+            .into()
+    }
+
+    /// Validate the assertion against the expected relying party and challenge.
+    ///
+    /// The relying party id hash and the user presence flag are checked from the authenticator
+    /// data, and the type, challenge and origin from the client data. If `user_verification` is
+    /// set, the user verified flag is required as well.
+    ///
+    /// The origin is compared verbatim with its serialization in the client data, so an origin
+    /// with characters escaped by the serialization is never matched.
+    pub fn validate(
+        &self,
+        rp: &RelyingParty,
+        challenge: &Message,
+        user_verification: bool,
+    ) -> Result<(), Error> {
+        let data = self.authenticator_data;
+
+        if data.len() < AUTHENTICATOR_DATA_MIN_LEN {
+            return Err(Error::InvalidAuthenticatorData);
+        }
+
+        if data[..FLAGS_OFFSET] != *Hasher::hash(rp.id.as_bytes()) {
+            return Err(Error::InvalidAuthenticatorData);
+        }
+
+        let flags = data[FLAGS_OFFSET];
+        let required = if user_verification {
+            FLAG_UP | FLAG_UV
+        } else {
+            FLAG_UP
+        };
+
+        if flags & required != required {
+            return Err(Error::InvalidAuthenticatorData);
+        }
+
+        let mut expected = [0u8; CHALLENGE_LEN];
+
+        URL_SAFE_NO_PAD
+            .encode_slice(challenge, &mut expected)
+            .expect("A message is always encoded with the challenge length");
+
+        let client_data = self
+            .client_data_json
+            .strip_prefix(CLIENT_DATA_PREFIX)
+            .and_then(|c| c.strip_prefix(&expected[..]))
+            .and_then(|c| c.strip_prefix(CLIENT_DATA_ORIGIN))
+            .and_then(|c| c.strip_prefix(rp.origin.as_bytes()))
+            .ok_or(Error::InvalidClientData)?;
+
+        // The origin must be the whole string, and not a prefix of it
+        match client_data {
+            [b'"', b',' | b'}', ..] => Ok(()),
+            _ => Err(Error::InvalidClientData),
+        }
+    }
+
+    /// Recover the public key of the passkey from a signature normalized by
+    /// [`Assertion::verify_der`].
+    ///
+    /// The assertion is validated with [`Assertion::validate`] before the recovery.
+    pub fn recover(
+        &self,
+        signature: Signature,
+        rp: &RelyingParty,
+        challenge: &Message,
+        user_verification: bool,
+    ) -> Result<PublicKey, Error> {
+        self.validate(rp, challenge, user_verification)?;
+
+        signature.recover(&self.message())
+    }
+
+    /// Verify a signature normalized by [`Assertion::verify_der`].
+    ///
+    /// The assertion is validated with [`Assertion::validate`] before the verification.
+    pub fn verify(
+        &self,
+        signature: Signature,
+        pk: &PublicKey,
+        rp: &RelyingParty,
+        challenge: &Message,
+        user_verification: bool,
+    ) -> Result<(), Error> {
+        self.validate(rp, challenge, user_verification)?;
+
+        signature.verify(pk, &self.message())
+    }
+
+    /// Verify the ASN.1 DER signature produced by the authenticator.
+    ///
+    /// Authenticators don't normalize their signatures, so the upper-S form is accepted. On
+    /// success, the signature is returned in the lower-S, recoverable form of [`Signature`], so
+    /// the public key can be recovered from it with [`Assertion::recover`].
+    ///
+    /// Signatures with a nonce point that can't be represented by the recovery id, which happens
+    /// with a probability of about 2^-32, are rejected.
+    pub fn verify_der(
+        &self,
+        signature: &[u8],
+        pk: &PublicKey,
+        rp: &RelyingParty,
+        challenge: &Message,
+        user_verification: bool,
+    ) -> Result<Signature, Error> {
+        self.validate(rp, challenge, user_verification)?;
+
+        let message = self.message();
+
+        // The authenticator doesn't provide the recovery id, so it is computed from the public key
+        Self::candidates(signature)?
+            .into_iter()
+            .find(|signature| signature.recover(&message).as_ref() == Ok(pk))
+            .ok_or(Error::InvalidSignature)
+    }
+
+    /// Candidate public keys that verify the ASN.1 DER signature produced by the authenticator.
+    ///
+    /// The authenticator doesn't provide the recovery id, so two keys, ordered by the parity of
+    /// the `y` coordinate of the nonce point, verify the signature. The key of the passkey is the
+    /// one also recovered from another assertion of the same credential, or the one matching
+    /// its registered key; it isn't one of them for nonce points that can't be represented by
+    /// the recovery id, as in [`Assertion::verify_der`].
+    ///
+    /// The assertion is validated with [`Assertion::validate`] before the recovery.
+    pub fn candidates_der(
+        &self,
+        signature: &[u8],
+        rp: &RelyingParty,
+        challenge: &Message,
+        user_verification: bool,
+    ) -> Result<[PublicKey; 2], Error> {
+        self.validate(rp, challenge, user_verification)?;
+
+        let message = self.message();
+        let [even, odd] = Self::candidates(signature)?;
+
+        Ok([even.recover(&message)?, odd.recover(&message)?])
+    }
+
+    // Lower-S form of a DER signature, with both values of the recovery id
+    fn candidates(signature: &[u8]) -> Result<[Signature; 2], Error> {
+        let signature = P256Signature::from_der(signature).map_err(|_| Error::InvalidSignature)?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        // Safety: the fixed-size representation is `r || s`
+        let even = unsafe { Signature::from_slice_unchecked(&signature.to_bytes()) };
+        let mut odd = even;
+
+        odd.as_mut()[32] |= 0x80;
+
+        Ok([even, odd])
+    }
+}
@@ -99,16 +99,12 @@ impl fmt::Display for SecretKey {
     }
 }
 
-#[cfg(feature = "std")]
-mod use_std {
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
     use super::*;
     use crate::{Error, PublicKey};
-    use coins_bip32::path::DerivationPath;
-    use coins_bip39::{English, Mnemonic};
-    use core::borrow::Borrow;
+
     use core::str;
-    use secp256k1::{Error as Secp256k1Error, SecretKey as Secp256k1SecretKey};
-    use std::str::FromStr;
 
     #[cfg(feature = "random")]
     use rand::{
@@ -116,8 +112,6 @@ mod use_std {
         Rng,
     };
 
-    pub type W = English;
-
     impl SecretKey {
         /// Create a new random secret
         #[cfg(feature = "random")]
@@ -134,22 +128,13 @@ mod use_std {
             // We don't call `Secp256k1SecretKey::new` here because the `rand` requirements
             // are outdated and inconsistent.
 
-            use secp256k1::ffi::{self, CPtr};
-
             let mut secret = Bytes32::zeroed();
 
             loop {
                 rng.fill(secret.as_mut());
 
-                // Safety: FFI call
-                let overflow = unsafe {
-                    ffi::secp256k1_ec_seckey_verify(
-                        ffi::secp256k1_context_no_precomp,
-                        secret.as_c_ptr(),
-                    )
-                };
-
-                if overflow != 0 {
+                // Safety: `Bytes32` is guaranteed to have `Self::LEN` bytes
+                if unsafe { Self::is_slice_in_field_unchecked(secret.as_ref()) } {
                     break;
                 }
             }
@@ -157,48 +142,6 @@ mod use_std {
             Self(secret)
         }
 
-        /// Generate a new secret key from a mnemonic phrase and its derivation path.
-        /// Both are passed as `&str`. If you want to manually create a `DerivationPath`
-        /// and `Mnemonic`, use [`SecretKey::new_from_mnemonic`].
-        /// The derivation path is a list of integers, each representing a child index.
-        pub fn new_from_mnemonic_phrase_with_path(phrase: &str, path: &str) -> Result<Self, Error> {
-            let mnemonic = Mnemonic::<W>::new_from_phrase(phrase)?;
-            let path = DerivationPath::from_str(path)?;
-            Self::new_from_mnemonic(path, mnemonic)
-        }
-
-        /// Generate a new secret key from a `DerivationPath` and `Mnemonic`.
-        /// If you want to pass strings instead, use [`SecretKey::new_from_mnemonic_phrase_with_path`].
-        pub fn new_from_mnemonic(d: DerivationPath, m: Mnemonic<W>) -> Result<Self, Error> {
-            let derived_priv_key = m.derive_key(d, None)?;
-            let key: &coins_bip32::prelude::SigningKey = derived_priv_key.as_ref();
-
-            // Safety: this slice will always be of the expected length (`Bytes32`)
-            // because it will be a `Secp256k` secret key, coming from
-            // `coins_bip32::prelude::SigningKey`, which is a 256-bit (32-byte) scalar.
-            Ok(unsafe { SecretKey::from_slice_unchecked(key.to_bytes().as_ref()) })
-        }
-
-        /// Check if the provided slice represents a scalar that fits the field.
-        ///
-        /// # Safety
-        ///
-        /// This function extends the unsafety of
-        /// [`SecretKey::as_ref_unchecked`].
-        pub unsafe fn is_slice_in_field_unchecked(slice: &[u8]) -> bool {
-            use secp256k1::ffi::{self, CPtr};
-
-            let secret = Self::as_ref_unchecked(slice);
-
-            // Safety: FFI call
-            let overflow = ffi::secp256k1_ec_seckey_verify(
-                ffi::secp256k1_context_no_precomp,
-                secret.as_c_ptr(),
-            );
-
-            overflow != 0
-        }
-
         /// Check if the secret key representation fits the scalar field.
         pub fn is_in_field(&self) -> bool {
             // Safety: struct is guaranteed to reference itself with correct len
@@ -232,7 +175,7 @@ mod use_std {
 
         fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
             Bytes32::try_from(slice)
-                .map_err(|_| Secp256k1Error::InvalidSecretKey.into())
+                .map_err(|_| Error::InvalidSecretKey)
                 .and_then(SecretKey::try_from)
         }
     }
@@ -242,19 +185,11 @@ mod use_std {
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
             Bytes32::from_str(s)
-                .map_err(|_| Secp256k1Error::InvalidSecretKey.into())
+                .map_err(|_| Error::InvalidSecretKey)
                 .and_then(SecretKey::try_from)
         }
     }
 
-    impl Borrow<Secp256k1SecretKey> for SecretKey {
-        fn borrow(&self) -> &Secp256k1SecretKey {
-            // Safety: field checked. The memory representation of the secp256k1 key is
-            // `[u8; 32]`
-            unsafe { &*(self.as_ref().as_ptr() as *const Secp256k1SecretKey) }
-        }
-    }
-
     #[cfg(feature = "random")]
     impl rand::Fill for SecretKey {
         fn try_fill<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), rand::Error> {
@@ -271,3 +206,115 @@ mod use_std {
         }
     }
 }
+
+#[cfg(feature = "std")]
+mod use_std {
+    use super::*;
+    use crate::{Error, ExtendedSecretKey};
+    use coins_bip32::path::DerivationPath;
+    use coins_bip39::{Mnemonic, Wordlist};
+    use secp256k1::SecretKey as Secp256k1SecretKey;
+    use std::borrow::Borrow;
+    use std::str::FromStr;
+
+    impl SecretKey {
+        /// Generate a new secret key from a mnemonic phrase and its derivation path.
+        /// Both are passed as `&str`. If you want to manually create a `DerivationPath`
+        /// and `Mnemonic`, use [`SecretKey::new_from_mnemonic`].
+        /// The derivation path is a list of integers, each representing a child index.
+        /// The phrase can be in any language of [`MnemonicLanguage`](crate::MnemonicLanguage).
+        pub fn new_from_mnemonic_phrase_with_path(phrase: &str, path: &str) -> Result<Self, Error> {
+            Self::new_from_mnemonic_phrase_with_passphrase(phrase, path, "")
+        }
+
+        /// Generate a new secret key from a mnemonic phrase protected by a BIP-39
+        /// passphrase, and its derivation path.
+        pub fn new_from_mnemonic_phrase_with_passphrase(
+            phrase: &str,
+            path: &str,
+            passphrase: &str,
+        ) -> Result<Self, Error> {
+            let path = DerivationPath::from_str(path)?;
+            let key = ExtendedSecretKey::from_mnemonic_phrase_with_passphrase(phrase, passphrase)?;
+
+            Ok(key.derive_path(&path)?.secret_key())
+        }
+
+        /// Generate a new secret key from a `DerivationPath` and `Mnemonic`.
+        /// If you want to pass strings instead, use [`SecretKey::new_from_mnemonic_phrase_with_path`].
+        pub fn new_from_mnemonic<L: Wordlist>(
+            d: DerivationPath,
+            m: Mnemonic<L>,
+        ) -> Result<Self, Error> {
+            Self::new_from_mnemonic_with_passphrase(d, m, "")
+        }
+
+        /// Generate a new secret key from a `DerivationPath` and a `Mnemonic` protected by a
+        /// BIP-39 passphrase.
+        pub fn new_from_mnemonic_with_passphrase<L: Wordlist>(
+            d: DerivationPath,
+            m: Mnemonic<L>,
+            passphrase: &str,
+        ) -> Result<Self, Error> {
+            let key = ExtendedSecretKey::from_mnemonic_with_passphrase(&m, passphrase)?;
+
+            Ok(key.derive_path(&d)?.secret_key())
+        }
+
+        /// Check if the provided slice represents a scalar that fits the field.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`SecretKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_field_unchecked(slice: &[u8]) -> bool {
+            use secp256k1::ffi::{self, CPtr};
+
+            let secret = Self::as_ref_unchecked(slice);
+
+            // Safety: FFI call
+            let overflow = ffi::secp256k1_ec_seckey_verify(
+                ffi::secp256k1_context_no_precomp,
+                secret.as_c_ptr(),
+            );
+
+            overflow != 0
+        }
+    }
+
+    impl Borrow<Secp256k1SecretKey> for SecretKey {
+        fn borrow(&self) -> &Secp256k1SecretKey {
+            // Safety: field checked. The memory representation of the secp256k1 key is
+            // `[u8; 32]`
+            unsafe { &*(self.as_ref().as_ptr() as *const Secp256k1SecretKey) }
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use super::*;
+
+    use k256::ecdsa::SigningKey;
+
+    impl SecretKey {
+        /// Check if the provided slice represents a scalar that fits the field.
+        ///
+        /// # Safety
+        ///
+        /// This function extends the unsafety of
+        /// [`SecretKey::as_ref_unchecked`].
+        pub unsafe fn is_slice_in_field_unchecked(slice: &[u8]) -> bool {
+            let secret = Self::as_ref_unchecked(slice);
+
+            // Rejects both the zero scalar and scalars that overflow the curve order, matching
+            // `secp256k1_ec_seckey_verify`
+            SigningKey::from_bytes(secret.as_ref()).is_ok()
+        }
+
+        // Internal API - the caller is expected to hold a field-checked secret
+        pub(crate) fn to_k256(&self) -> SigningKey {
+            SigningKey::from_bytes(self.as_ref()).expect("Secret key is not in the field")
+        }
+    }
+}
@@ -129,13 +129,263 @@ impl str::FromStr for Signature {
     }
 }
 
+/// ASN.1 DER encoding of an ECDSA signature, as defined in SEC 1
+///
+/// Produced by [`Signature::to_der`] without allocation. The recovery id isn't part of the
+/// encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DerSignature {
+    bytes: [u8; DerSignature::MAX_LEN],
+    len: usize,
+}
+
+impl DerSignature {
+    /// Maximum memory length of an encoded secp256k1 signature
+    pub const MAX_LEN: usize = 72;
+
+    #[cfg(any(feature = "std", feature = "k256"))]
+    pub(crate) fn from_slice(slice: &[u8]) -> Self {
+        let mut bytes = [0u8; Self::MAX_LEN];
+
+        bytes[..slice.len()].copy_from_slice(slice);
+
+        Self {
+            bytes,
+            len: slice.len(),
+        }
+    }
+
+    /// Encoded bytes of the signature
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Deref for DerSignature {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for DerSignature {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Debug for DerSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+
+        self.as_bytes()
+            .iter()
+            .try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "k256"))]
+mod use_backend {
+    use crate::{Error, Message, PublicKey, Signature};
+
+    impl Signature {
+        /// Truncate the recovery id from the signature, producing a valid `secp256k1`
+        /// representation.
+        pub(crate) fn truncate_recovery_id(&mut self) {
+            self.as_mut()[32] &= 0x7f;
+        }
+
+        /// Compute the recovery id of a lower-S `r || s` signature by trial recovery against the
+        /// expected public key.
+        pub(crate) fn with_recovery_id(
+            compact: [u8; Signature::LEN],
+            pk: &PublicKey,
+            message: &Message,
+        ) -> Result<Self, Error> {
+            [false, true]
+                .into_iter()
+                .map(|v| {
+                    // Safety: the signature is checked by the recovery
+                    let mut signature = unsafe { Signature::from_bytes_unchecked(compact) };
+
+                    signature.as_mut()[32] |= (v as u8) << 7;
+
+                    signature
+                })
+                .find(|signature| signature.recover(message).as_ref() == Ok(pk))
+                .ok_or(Error::InvalidSignature)
+        }
+
+        /// Verify a signature produced by [`Signature::sign`]
+        ///
+        /// The public key is recovered and compared to `pk`, so the recovery id is bound to the
+        /// key: the same `r || s` with a flipped recovery id recovers another key, and is
+        /// rejected. Only canonical signatures, as checked by [`Signature::is_canonical`], are
+        /// accepted.
+        ///
+        /// It takes the signature as owned because this operation is not idempotent. The taken
+        /// signature will not be recoverable. Signatures are meant to be single use, so this
+        /// avoids unnecessary copy.
+        pub fn verify(self, pk: &PublicKey, message: &Message) -> Result<(), Error> {
+            if !self.is_canonical() {
+                return Err(Error::InvalidSignature);
+            }
+
+            self.recover(message)
+                .and_then(|pk_p| (pk == &pk_p).then_some(()).ok_or(Error::InvalidSignature))
+        }
+
+        /// Recover the public key from a signature performed with [`Signature::sign`], rejecting
+        /// malleable signatures.
+        ///
+        /// Differently from [`Signature::recover`], signatures that aren't canonical, as checked
+        /// by [`Signature::is_canonical`], fail with [`Error::NonCanonicalSignature`] instead of
+        /// being accepted in their upper-S form or recovered from a zeroed fallback.
+        ///
+        /// The recovery id can't be checked without the expected key: flipping it recovers
+        /// another key, which must be compared to the expected signer, as done by
+        /// [`Signature::verify_strict`].
+        pub fn recover_strict(self, message: &Message) -> Result<PublicKey, Error> {
+            if !self.is_canonical() {
+                return Err(Error::NonCanonicalSignature);
+            }
+
+            self.recover(message)
+        }
+
+        /// Verify a signature produced by [`Signature::sign`], rejecting malleable signatures.
+        ///
+        /// Differently from [`Signature::verify`], signatures that aren't canonical, as checked
+        /// by [`Signature::is_canonical`], fail with [`Error::NonCanonicalSignature`]. The
+        /// recovery id is bound to the key as in [`Signature::verify`].
+        pub fn verify_strict(self, pk: &PublicKey, message: &Message) -> Result<(), Error> {
+            if !self.is_canonical() {
+                return Err(Error::NonCanonicalSignature);
+            }
+
+            self.verify(pk, message)
+        }
+
+        /// Recover the public keys of a batch of signatures performed with
+        /// [`Signature::sign`]
+        ///
+        /// The outcome of the recovery of `items[i]` is written to `results[i]`, so the caller
+        /// can pinpoint the invalid items. If the `std` feature is enabled, the batch is split
+        /// across the available threads.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `items` and `results` don't have the same length.
+        pub fn recover_batch_into(
+            items: &[(Signature, Message)],
+            results: &mut [Result<PublicKey, Error>],
+        ) {
+            batch_into(items, results, |(signature, message)| {
+                signature.recover(message)
+            })
+        }
+
+        /// Verify a batch of signatures produced by [`Signature::sign`]
+        ///
+        /// The outcome of the verification of `items[i]` is written to `results[i]`, so the
+        /// caller can pinpoint the invalid items. If the `std` feature is enabled, the batch is
+        /// split across the available threads.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `items` and `results` don't have the same length.
+        pub fn verify_batch_into(
+            items: &[(Signature, PublicKey, Message)],
+            results: &mut [Result<(), Error>],
+        ) {
+            batch_into(items, results, |(signature, pk, message)| {
+                signature.verify(pk, message)
+            })
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn batch_into<I, O, F>(items: &[I], results: &mut [O], f: F)
+    where
+        F: Fn(&I) -> O,
+    {
+        assert_eq!(
+            items.len(),
+            results.len(),
+            "Every item requires a result slot"
+        );
+
+        items
+            .iter()
+            .zip(results.iter_mut())
+            .for_each(|(item, result)| *result = f(item));
+    }
+
+    #[cfg(feature = "std")]
+    fn batch_into<I, O, F>(items: &[I], results: &mut [O], f: F)
+    where
+        I: Sync,
+        O: Send,
+        F: Fn(&I) -> O + Sync,
+    {
+        use std::thread;
+
+        // Minimum number of items delegated to a thread, so the spawn cost is amortized
+        const MIN_CHUNK_LEN: usize = 16;
+
+        assert_eq!(
+            items.len(),
+            results.len(),
+            "Every item requires a result slot"
+        );
+
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+
+        let chunk_len = items.len().div_ceil(threads).max(MIN_CHUNK_LEN);
+        let f = &f;
+
+        if items.len() <= chunk_len {
+            items
+                .iter()
+                .zip(results.iter_mut())
+                .for_each(|(item, result)| *result = f(item));
+
+            return;
+        }
+
+        thread::scope(|s| {
+            items
+                .chunks(chunk_len)
+                .zip(results.chunks_mut(chunk_len))
+                .for_each(|(items, results)| {
+                    s.spawn(move || {
+                        items
+                            .iter()
+                            .zip(results.iter_mut())
+                            .for_each(|(item, result)| *result = f(item))
+                    });
+                });
+        });
+    }
+}
+
 #[cfg(feature = "std")]
 mod use_std {
     use crate::{Error, Message, PublicKey, SecretKey, Signature};
 
+    use crate::signature::DerSignature;
+
     use lazy_static::lazy_static;
     use secp256k1::{
-        ecdsa::{RecoverableSignature as SecpRecoverableSignature, RecoveryId},
+        ecdsa::{
+            RecoverableSignature as SecpRecoverableSignature, RecoveryId,
+            Signature as SecpSignature,
+        },
         Secp256k1,
     };
 
@@ -177,12 +427,6 @@ mod use_std {
             unsafe { Signature::from_bytes_unchecked(signature) }
         }
 
-        /// Truncate the recovery id from the signature, producing a valid `secp256k1`
-        /// representation.
-        pub(crate) fn truncate_recovery_id(&mut self) {
-            self.as_mut()[32] &= 0x7f;
-        }
-
         /// Sign a given message and compress the `v` to the signature
         ///
         /// The compression scheme is described in
@@ -213,18 +457,258 @@ mod use_std {
             Ok(pk)
         }
 
-        /// Verify a signature produced by [`Signature::sign`]
+        /// Convert an ASN.1 DER signature, as produced by external signers, to the compact
+        /// representation of [`Signature::sign`].
+        ///
+        /// The upper-S form is normalized, and the recovery id, absent from the encoding, is
+        /// computed by trial recovery against the expected public key of the signer.
+        pub fn from_der(der: &[u8], pk: &PublicKey, message: &Message) -> Result<Self, Error> {
+            let mut signature = SecpSignature::from_der(der).map_err(|_| Error::InvalidDer)?;
+
+            signature.normalize_s();
+
+            Self::with_recovery_id(signature.serialize_compact(), pk, message)
+        }
+
+        /// Encode the signature to ASN.1 DER, in its lower-S form.
+        ///
+        /// The recovery id is not part of the encoding.
+        pub fn to_der(&self) -> Result<DerSignature, Error> {
+            let mut signature = self.to_secp_standard()?;
+
+            signature.normalize_s();
+
+            Ok(DerSignature::from_slice(&signature.serialize_der()))
+        }
+
+        // Strict parse of the `r || s` components, without the zeroed fallback of `to_secp`
+        fn to_secp_standard(&self) -> Result<SecpSignature, Error> {
+            let mut signature = *self;
+
+            signature.truncate_recovery_id();
+
+            // libsecp256k1 parses zeroed components, so they are rejected here to be consistent
+            // with the `k256` backend
+            if signature
+                .chunks_exact(32)
+                .any(|c| c.iter().all(|b| *b == 0))
+            {
+                return Err(Error::InvalidSignature);
+            }
+
+            SecpSignature::from_compact(signature.as_ref()).map_err(|_| Error::InvalidSignature)
+        }
+
+        /// Check if the signature is canonical: both components are in the field and `s` is in
+        /// its lower form, as produced by [`Signature::sign`].
+        ///
+        /// The recovery id isn't checked, since both values are canonical; it is bound to the
+        /// expected key by [`Signature::verify`] and [`Signature::verify_strict`].
+        pub fn is_canonical(&self) -> bool {
+            self.to_secp_standard()
+                .map(|signature| {
+                    let mut normalized = signature;
+
+                    normalized.normalize_s();
+
+                    normalized == signature
+                })
+                .unwrap_or(false)
+        }
+
+        /// Normalize the signature to its lower-S form, flipping the recovery id so the
+        /// recovered public key is preserved.
+        ///
+        /// Signatures with components out of the field are left untouched.
+        pub fn normalize_s(&mut self) {
+            if let Ok(signature) = self.to_secp_standard() {
+                let mut normalized = signature;
+
+                normalized.normalize_s();
+
+                if normalized != signature {
+                    let v = self[32] & 0x80;
+
+                    self.as_mut()
+                        .copy_from_slice(&normalized.serialize_compact());
+                    self.as_mut()[32] |= v ^ 0x80;
+                }
+            }
+        }
+
+        /// Recover the public keys of a batch of signatures performed with
+        /// [`Signature::sign`]
+        ///
+        /// The outcome of the recovery of `items[i]` is returned at index `i`. Check
+        /// [`Signature::recover_batch_into`] for an allocation-free variant.
+        pub fn recover_batch(items: &[(Signature, Message)]) -> Vec<Result<PublicKey, Error>> {
+            let mut results = vec![Err(Error::InvalidSignature); items.len()];
+
+            Self::recover_batch_into(items, &mut results);
+
+            results
+        }
+
+        /// Verify a batch of signatures produced by [`Signature::sign`]
+        ///
+        /// Returns the indices of the items that failed the verification, if any. Check
+        /// [`Signature::verify_batch_into`] for an allocation-free variant.
+        pub fn verify_batch(items: &[(Signature, PublicKey, Message)]) -> Result<(), Vec<usize>> {
+            let mut results = vec![Ok(()); items.len()];
+
+            Self::verify_batch_into(items, &mut results);
+
+            let failed: Vec<usize> = results
+                .iter()
+                .enumerate()
+                .filter_map(|(i, r)| r.is_err().then_some(i))
+                .collect();
+
+            failed.is_empty().then_some(()).ok_or(failed)
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "k256"))]
+mod use_k256 {
+    use crate::{Error, Message, PublicKey, SecretKey, Signature};
+
+    use k256::ecdsa::recoverable::{Id, Signature as K256RecoverableSignature};
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use k256::ecdsa::Signature as K256Signature;
+
+    use crate::signature::DerSignature;
+
+    impl Signature {
+        // Internal API - this isn't meant to be made public because some assumptions and pre-checks
+        // are performed prior to this call
+        pub(crate) fn to_k256(&mut self) -> Result<K256RecoverableSignature, Error> {
+            let v = self.as_mut()[32] >> 7;
+
+            self.truncate_recovery_id();
+
+            let v = Id::new(v).expect("The recovery ID is always a single bit");
+
+            // Differently from the secp256k1 backend, `k256` will not accept zeroed components,
+            // so an unparseable signature is reported as invalid instead of zeroed
+            K256Signature::try_from(self.as_ref())
+                .and_then(|signature| K256RecoverableSignature::new(&signature, v))
+                .map_err(|_| Error::InvalidSignature)
+        }
+
+        pub(crate) fn from_k256(signature: K256RecoverableSignature) -> Self {
+            let v = u8::from(signature.recovery_id());
+
+            // Safety: the recoverable representation is `r || s || v`, so the first
+            // `Signature::LEN` bytes are the compact signature
+            let mut signature = unsafe { Signature::from_slice_unchecked(signature.as_ref()) };
+
+            signature.as_mut()[32] |= v << 7;
+
+            signature
+        }
+
+        /// Sign a given message and compress the `v` to the signature
+        ///
+        /// The compression scheme is described in
+        /// <https://github.com/FuelLabs/fuel-specs/blob/master/specs/protocol/cryptographic_primitives.md#public-key-cryptography>
+        pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+            let secret = secret.to_k256();
+            let message = message.to_k256();
+
+            // `k256` uses a deterministic RFC-6979 nonce with HMAC-SHA256 and no additional data.
+            // This is the default nonce function of libsecp256k1, so both backends produce the
+            // same signature.
+            let signature: K256RecoverableSignature = secret
+                .sign_prehash(message)
+                .expect("Signing a 32-byte digest with a field-checked secret is infallible");
+
+            Signature::from_k256(signature)
+        }
+
+        /// Recover the public key from a signature performed with
+        /// [`Signature::sign`]
         ///
         /// It takes the signature as owned because this operation is not idempotent. The taken
         /// signature will not be recoverable. Signatures are meant to be single use, so this
         /// avoids unnecessary copy.
-        pub fn verify(self, pk: &PublicKey, message: &Message) -> Result<(), Error> {
-            // TODO evaluate if its worthy to use native verify
-            //
-            // https://github.com/FuelLabs/fuel-crypto/issues/4
+        pub fn recover(mut self, message: &Message) -> Result<PublicKey, Error> {
+            let signature = self.to_k256()?;
+            let message = message.to_k256();
 
-            self.recover(message)
-                .and_then(|pk_p| (pk == &pk_p).then_some(()).ok_or(Error::InvalidSignature))
+            let pk = signature
+                .recover_verifying_key_from_digest_bytes(message)
+                .map(|pk| PublicKey::from_k256(&pk))
+                .map_err(|_| Error::InvalidSignature)?;
+
+            Ok(pk)
+        }
+
+        /// Convert an ASN.1 DER signature, as produced by external signers, to the compact
+        /// representation of [`Signature::sign`].
+        ///
+        /// The upper-S form is normalized, and the recovery id, absent from the encoding, is
+        /// computed by trial recovery against the expected public key of the signer.
+        pub fn from_der(der: &[u8], pk: &PublicKey, message: &Message) -> Result<Self, Error> {
+            let signature = K256Signature::from_der(der).map_err(|_| Error::InvalidDer)?;
+            let signature = signature.normalize_s().unwrap_or(signature);
+
+            let mut compact = [0u8; Signature::LEN];
+
+            compact.copy_from_slice(signature.as_ref());
+
+            Self::with_recovery_id(compact, pk, message)
+        }
+
+        /// Encode the signature to ASN.1 DER, in its lower-S form.
+        ///
+        /// The recovery id is not part of the encoding.
+        pub fn to_der(&self) -> Result<DerSignature, Error> {
+            let mut signature = *self;
+
+            signature.truncate_recovery_id();
+
+            let signature =
+                K256Signature::try_from(signature.as_ref()).map_err(|_| Error::InvalidSignature)?;
+            let signature = signature.normalize_s().unwrap_or(signature);
+
+            Ok(DerSignature::from_slice(signature.to_der().as_bytes()))
+        }
+
+        /// Check if the signature is canonical: both components are in the field and `s` is in
+        /// its lower form, as produced by [`Signature::sign`].
+        ///
+        /// The recovery id isn't checked, since both values are canonical; it is bound to the
+        /// expected key by [`Signature::verify`] and [`Signature::verify_strict`].
+        pub fn is_canonical(&self) -> bool {
+            let mut signature = *self;
+
+            signature.truncate_recovery_id();
+
+            K256Signature::try_from(signature.as_ref())
+                .map(|signature| signature.normalize_s().is_none())
+                .unwrap_or(false)
+        }
+
+        /// Normalize the signature to its lower-S form, flipping the recovery id so the
+        /// recovered public key is preserved.
+        ///
+        /// Signatures with components out of the field are left untouched.
+        pub fn normalize_s(&mut self) {
+            let mut signature = *self;
+
+            signature.truncate_recovery_id();
+
+            let normalized = K256Signature::try_from(signature.as_ref())
+                .ok()
+                .and_then(|signature| signature.normalize_s());
+
+            if let Some(normalized) = normalized {
+                let v = self[32] & 0x80;
+
+                self.as_mut().copy_from_slice(normalized.as_ref());
+                self.as_mut()[32] |= v ^ 0x80;
+            }
         }
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "std", feature = "k256"))]
+use crate::keystore::{Seal, SealedKeystore};
 use crate::{Error, Keystore, Message, PublicKey, SecretKey, Signature};
 
 use borrown::Borrown;
@@ -39,31 +41,24 @@ pub trait Signer {
     }
 
     /// Sign a given message with the secret key identified by `id`
+    #[cfg(not(any(feature = "std", feature = "k256")))]
     fn sign(
         &self,
         id: &<Self::Keystore as Keystore>::KeyId,
         message: &Message,
-    ) -> Result<Signature, Self::Error> {
-        let secret = self.id_secret(id)?;
-
-        self.sign_with_key(secret.as_ref(), message)
-    }
-
-    /// Sign a given message with the provided key
-    #[cfg(not(feature = "std"))]
-    fn sign_with_key(
-        &self,
-        secret: &SecretKey,
-        message: &Message,
     ) -> Result<Signature, Self::Error>;
 
-    /// Sign a given message with the provided key
-    #[cfg(feature = "std")]
-    fn sign_with_key(
+    /// Sign a given message with the secret key identified by `id`
+    #[cfg(any(feature = "std", feature = "k256"))]
+    fn sign(
         &self,
-        secret: &SecretKey,
+        id: &<Self::Keystore as Keystore>::KeyId,
         message: &Message,
     ) -> Result<Signature, Self::Error> {
-        Ok(Signature::sign(secret, message))
+        let signature = self
+            .keystore()?
+            .with_secret(id, Seal::new(), |secret| Signature::sign(secret, message))?;
+
+        Ok(signature)
     }
 }
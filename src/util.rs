@@ -0,0 +1,28 @@
+//! Crate-private helpers shared by the string representations.
+
+/// Decode a hex string into `bytes`, which must be half its length.
+///
+/// Only hex digits are accepted; `u8::from_str_radix` would also take a leading `+` in each byte.
+pub(crate) fn decode_hex(hex: &[u8], bytes: &mut [u8]) -> Option<()> {
+    if hex.len() != bytes.len() * 2 {
+        return None;
+    }
+
+    bytes
+        .iter_mut()
+        .zip(hex.chunks_exact(2))
+        .try_for_each(|(b, c)| {
+            *b = hex_digit(c[0])? << 4 | hex_digit(c[1])?;
+
+            Some(())
+        })
+}
+
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
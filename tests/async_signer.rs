@@ -0,0 +1,303 @@
+use fuel_crypto::{
+    AsyncSigner, Error, KeyId, MemoryKeystore, Message, PublicKey, SecretKey, Signature, Signer,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::{mpsc, Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, JoinHandle, Thread};
+
+// Minimal executor, parking the thread until the future is woken
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+struct Slot<T> {
+    value: Option<Result<T, Error>>,
+    waker: Option<Waker>,
+}
+
+// Sending half of a pending response; dropping it unanswered fails the response
+struct Reply<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> Reply<T> {
+    fn send(self, value: Result<T, Error>) {
+        let mut slot = self.0.lock().expect("Poisoned slot");
+
+        slot.value.replace(value);
+
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Reply<T> {
+    fn drop(&mut self) {
+        let mut slot = self.0.lock().expect("Poisoned slot");
+
+        if slot.value.is_none() {
+            slot.value.replace(Err(Error::KeystoreNotAvailable));
+
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+struct Response<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> Future for Response<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.lock().expect("Poisoned slot");
+
+        match slot.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                slot.waker.replace(cx.waker().clone());
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn channel<T>() -> (Reply<T>, Response<T>) {
+    let slot = Arc::new(Mutex::new(Slot {
+        value: None,
+        waker: None,
+    }));
+
+    (Reply(Arc::clone(&slot)), Response(slot))
+}
+
+enum Request {
+    Public(KeyId, Reply<PublicKey>),
+    Sign(KeyId, Message, Reply<Signature>),
+}
+
+// Mock remote signer: the keys live in the server thread, and only the public keys and
+// signatures are sent back to the clients
+struct RemoteServer {
+    handle: JoinHandle<()>,
+}
+
+impl RemoteServer {
+    fn spawn(keystore: MemoryKeystore) -> (Self, RemoteClient) {
+        let (requests, receiver) = mpsc::channel::<Request>();
+
+        let handle = thread::spawn(move || {
+            for request in receiver {
+                match request {
+                    Request::Public(id, reply) => {
+                        reply.send(keystore.id_public(&id).map(|public| public.into_owned()))
+                    }
+
+                    Request::Sign(id, message, reply) => {
+                        reply.send(Signer::sign(&keystore, &id, &message))
+                    }
+                }
+            }
+        });
+
+        (Self { handle }, RemoteClient { requests })
+    }
+
+    // The server stops once all its clients are dropped
+    fn join(self) {
+        self.handle.join().expect("Server panicked");
+    }
+}
+
+#[derive(Clone)]
+struct RemoteClient {
+    requests: mpsc::Sender<Request>,
+}
+
+impl AsyncSigner for RemoteClient {
+    type Error = Error;
+    type KeyId = KeyId;
+
+    async fn public(&self, id: &KeyId) -> Result<PublicKey, Error> {
+        let (reply, response) = channel();
+
+        self.requests
+            .send(Request::Public(id.clone(), reply))
+            .map_err(|_| Error::KeystoreNotAvailable)?;
+
+        response.await
+    }
+
+    async fn sign(&self, id: &KeyId, message: &Message) -> Result<Signature, Error> {
+        let (reply, response) = channel();
+
+        self.requests
+            .send(Request::Sign(id.clone(), *message, reply))
+            .map_err(|_| Error::KeystoreNotAvailable)?;
+
+        response.await
+    }
+}
+
+// Generic over the signer, as a wallet would be
+async fn sign_and_recover<S>(signer: &S, id: &S::KeyId, message: &Message) -> Result<bool, S::Error>
+where
+    S: AsyncSigner,
+{
+    let public = signer.public(id).await?;
+    let signature = signer.sign(id, message).await?;
+
+    Ok(signature.recover(message) == Ok(public))
+}
+
+#[test]
+fn local_signer() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = MemoryKeystore::new();
+    let secret = SecretKey::random(rng);
+    let message = Message::new(b"No man is free who is not master of himself.");
+
+    let hash = keystore.insert("alice", secret).expect("Failed to insert");
+
+    let public = block_on(AsyncSigner::public(&keystore, &KeyId::from(hash)));
+    let signature = block_on(AsyncSigner::sign(
+        &keystore,
+        &KeyId::from("alice"),
+        &message,
+    ))
+    .expect("Failed to sign");
+
+    assert_eq!(Ok(secret.public_key()), public);
+    assert_eq!(Signature::sign(&secret, &message), signature);
+    assert_eq!(
+        Ok(true),
+        block_on(sign_and_recover(&keystore, &KeyId::from(hash), &message))
+    );
+
+    // The asynchronous signer has the same semantics as the synchronous one
+    let unknown = KeyId::from("bob");
+
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        block_on(AsyncSigner::public(&keystore, &unknown))
+    );
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        block_on(AsyncSigner::sign(&keystore, &unknown, &message))
+    );
+}
+
+#[test]
+fn remote_signer() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = MemoryKeystore::new();
+    let secret = SecretKey::random(rng);
+    let message = Message::new(b"Difficulties strengthen the mind, as labor does the body.");
+
+    keystore.insert("alice", secret).expect("Failed to insert");
+
+    let (server, client) = RemoteServer::spawn(keystore);
+    let alice = KeyId::from("alice");
+
+    let signature = block_on(client.sign(&alice, &message)).expect("Failed to sign");
+
+    assert_eq!(Signature::sign(&secret, &message), signature);
+    assert_eq!(Ok(secret.public_key()), block_on(client.public(&alice)));
+    assert_eq!(
+        Ok(true),
+        block_on(sign_and_recover(&client, &alice, &message))
+    );
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        block_on(client.sign(&KeyId::from("bob"), &message))
+    );
+
+    // Concurrent clients
+    let handles: Vec<_> = (0..8u8)
+        .map(|i| {
+            let client = client.clone();
+
+            thread::spawn(move || {
+                let message = Message::new([i]);
+                let signature =
+                    block_on(client.sign(&KeyId::from("alice"), &message)).expect("Failed to sign");
+
+                signature.recover(&message)
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(
+            Ok(secret.public_key()),
+            handle.join().expect("Client panicked")
+        );
+    }
+
+    drop(client);
+    server.join();
+}
+
+#[test]
+fn remote_signer_unavailable() {
+    let alice = KeyId::from("alice");
+    let message =
+        Message::new(b"He who fears death will never do anything worthy of a living man.");
+
+    // Stopped server
+    let (requests, receiver) = mpsc::channel();
+
+    drop(receiver);
+
+    let client = RemoteClient { requests };
+
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        block_on(client.sign(&alice, &message))
+    );
+
+    // Request dropped by the server without a reply
+    let (requests, receiver) = mpsc::channel::<Request>();
+    let server = thread::spawn(move || receiver.into_iter().for_each(drop));
+    let client = RemoteClient { requests };
+
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        block_on(client.sign(&alice, &message))
+    );
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        block_on(client.public(&alice))
+    );
+
+    drop(client);
+    server.join().expect("Server panicked");
+}
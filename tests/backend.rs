@@ -0,0 +1,184 @@
+use fuel_crypto::{Error, Message, PublicKey, SecretKey, Signature};
+use k256::ecdsa::recoverable::Signature as K256RecoverableSignature;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::SigningKey;
+use k256::elliptic_curve::ff::PrimeField;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use std::str::FromStr;
+
+#[test]
+fn sign_matches_libsecp256k1() {
+    // Produced with the libsecp256k1 backend
+    let secret = "3b940b5586823dfd02ae3b461bb4336b5ecbaefd6627aa922efc048fec0c881c";
+    let public = "1d152307c6b72b0ed0418b0e70cd80e7f5295b8d86f5722d3f5213fbd2394f36b7ce9c3e45905178455900b44abb308f3ef480481a4b2ee3f70aca157fde396a";
+    let signature = "4621558c975b46230801e9c6e9fdeb6f8577ac9b0cbc5bb81602fd0f1c43874fbb5ff766dd19562c9d3ee123cd46359692d22651b763677cc759048178b276e9";
+
+    let message = b"The unexamined life is not worth living.";
+    let message = Message::new(message);
+
+    let secret = SecretKey::from_str(secret).expect("Invalid secret key");
+    let public = PublicKey::from_str(public).expect("Invalid public key");
+    let signature = Signature::from_str(signature).expect("Invalid signature");
+
+    assert_eq!(public, secret.public_key());
+    assert_eq!(signature, Signature::sign(&secret, &message));
+
+    let recover = signature.recover(&message).expect("Failed to recover PK");
+
+    assert_eq!(public, recover);
+
+    signature
+        .verify(&public, &message)
+        .expect("Failed to verify signature");
+
+    // The recovery id is bound to the key
+    let mut flipped = signature;
+
+    flipped.as_mut()[32] ^= 0x80;
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        flipped.verify(&public, &message)
+    );
+}
+
+#[test]
+fn sign_matches_k256() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = b"Whereof one cannot speak, thereof one must be silent.";
+
+    for _ in 0..100 {
+        let message = Message::new(message);
+
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+        let signature = Signature::sign(&secret, &message);
+
+        let key = SigningKey::from_bytes(secret.as_ref()).expect("Invalid secret key");
+        let key_public = key.verifying_key().to_encoded_point(false);
+
+        assert_eq!(public.as_ref(), &key_public.as_bytes()[1..]);
+
+        let key_compressed = key.verifying_key().to_encoded_point(true);
+
+        assert_eq!(public.compress().as_ref(), key_compressed.as_bytes());
+        assert_eq!(Ok(public), public.compress().decompress());
+
+        let key_signature: K256RecoverableSignature =
+            key.sign_prehash(message.as_ref()).expect("Failed to sign");
+        let v = u8::from(key_signature.recovery_id());
+
+        let mut expected = [0u8; Signature::LEN];
+
+        expected.copy_from_slice(&key_signature.as_ref()[..Signature::LEN]);
+        expected[32] |= v << 7;
+
+        assert_eq!(expected, *signature);
+
+        let key_der = k256::ecdsa::Signature::from(key_signature).to_der();
+
+        assert_eq!(
+            key_der.as_bytes(),
+            signature.to_der().expect("Failed to encode").as_ref()
+        );
+        assert_eq!(
+            Ok(signature),
+            Signature::from_der(key_der.as_bytes(), &public, &message)
+        );
+
+        let recover = signature.recover(&message).expect("Failed to recover PK");
+
+        assert_eq!(public, recover);
+    }
+}
+
+#[test]
+fn curve_and_field_checks() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    assert!(secret.is_in_field());
+    assert!(public.is_in_curve());
+
+    // Zero and the curve order are out of the scalar field
+    let order = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+    assert_eq!(
+        Err(Error::InvalidSecretKey),
+        SecretKey::try_from([0u8; SecretKey::LEN].as_ref())
+    );
+    assert_eq!(Err(Error::InvalidSecretKey), SecretKey::from_str(order));
+
+    let mut p = public;
+
+    p.as_mut()[PublicKey::LEN - 1] ^= 1;
+
+    assert!(!p.is_in_curve());
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        PublicKey::try_from(p.as_ref())
+    );
+}
+
+#[test]
+fn normalize_s_flips_recovery_id() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        // Sign with a chosen `s` just below `n/2`, so the malleated `n - s` is representable
+        let secret = SecretKey::random(rng);
+        let d = Option::<Scalar>::from(Scalar::from_repr(*FieldBytes::from_slice(secret.as_ref())))
+            .expect("Invalid secret key");
+
+        let k = NonZeroScalar::random(&mut *rng);
+        let point = (ProjectivePoint::GENERATOR * *k).to_affine();
+        let point = point.to_encoded_point(false);
+
+        let x = FieldBytes::from_slice(point.x().expect("Not the identity"));
+        let r = Option::<Scalar>::from(Scalar::from_repr(*x)).expect("Unreduced x coordinate");
+        let v = point.y().expect("Not the identity")[31] & 1;
+
+        let half = -Option::<Scalar>::from(Scalar::from(2u64).invert()).expect("Invertible");
+        let s = half - Scalar::from(rng.gen::<u32>() as u64 + 1);
+        let z = s * *k - r * d;
+
+        let mut expected = [0u8; Signature::LEN];
+
+        expected[..32].copy_from_slice(&r.to_bytes());
+        expected[32..].copy_from_slice(&s.to_bytes());
+        expected[32] |= v << 7;
+
+        // Upper-S form, with the flipped recovery id
+        let mut malleated = [0u8; Signature::LEN];
+
+        malleated[..32].copy_from_slice(&r.to_bytes());
+        malleated[32..].copy_from_slice(&(-s).to_bytes());
+        malleated[32] |= (v ^ 1) << 7;
+
+        // Safety: the signature and message are checked by the recovery
+        let malleated = unsafe { Signature::from_bytes_unchecked(malleated) };
+        let message = unsafe { Message::from_bytes_unchecked(z.to_bytes().into()) };
+
+        assert!(!malleated.is_canonical());
+        assert_eq!(Ok(secret.public_key()), malleated.recover(&message));
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            malleated.recover_strict(&message)
+        );
+
+        let mut normalized = malleated;
+
+        normalized.normalize_s();
+
+        assert!(normalized.is_canonical());
+        assert_eq!(expected, *normalized);
+        assert_eq!(Ok(secret.public_key()), normalized.recover_strict(&message));
+    }
+}
@@ -0,0 +1,418 @@
+use coins_bip32::path::DerivationPath;
+use fuel_crypto::{
+    Error, ExtendedPublicKey, ExtendedSecretKey, FuelAccount, FuelDerivationPath, Message,
+    PublicKey, SecretKey, Signature,
+};
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+const HARDENED: u32 = 0x8000_0000;
+
+// Test vectors from BIP-32, as (path, xprv, xpub)
+const VECTOR_1_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+const VECTOR_1: &[(&str, &str, &str)] = &[
+    (
+        "m",
+        "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+        "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+    ),
+    (
+        "m/0'",
+        "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+        "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+    ),
+    (
+        "m/0'/1",
+        "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+        "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+    ),
+    (
+        "m/0'/1/2'",
+        "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+        "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+    ),
+    (
+        "m/0'/1/2'/2",
+        "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+        "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+    ),
+    (
+        "m/0'/1/2'/2/1000000000",
+        "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+        "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+    ),
+];
+
+const VECTOR_2_SEED: &str = "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542";
+const VECTOR_2: &[(&str, &str, &str)] = &[
+    (
+        "m",
+        "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+        "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB",
+    ),
+    (
+        "m/0",
+        "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+        "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH",
+    ),
+    (
+        "m/0/2147483647'",
+        "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+        "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a",
+    ),
+    (
+        "m/0/2147483647'/1",
+        "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+        "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon",
+    ),
+    (
+        "m/0/2147483647'/1/2147483646'",
+        "xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc",
+        "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL",
+    ),
+    (
+        "m/0/2147483647'/1/2147483646'/2",
+        "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j",
+        "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt",
+    ),
+];
+
+fn seed(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("Invalid hex seed"))
+        .collect()
+}
+
+fn check_vector(seed: &[u8], vector: &[(&str, &str, &str)]) {
+    let master = ExtendedSecretKey::from_seed(seed).expect("Failed to create master key");
+
+    let mut parent: Option<ExtendedSecretKey> = None;
+
+    for (path, xprv, xpub) in vector {
+        let path = DerivationPath::from_str(path).expect("Invalid path");
+        let key = master.derive_path(&path).expect("Failed to derive key");
+
+        assert_eq!(*xprv, key.to_xprv());
+        assert_eq!(*xpub, key.extended_public_key().to_xpub());
+        assert_eq!(*xpub, key.extended_public_key().to_string());
+
+        // Parsing
+        let parsed = ExtendedSecretKey::from_str(xprv).expect("Failed to parse xprv");
+        let parsed_public = ExtendedPublicKey::from_str(xpub).expect("Failed to parse xpub");
+
+        assert_eq!(key, parsed);
+        assert_eq!(key.extended_public_key(), parsed_public);
+        assert_eq!(key.public_key(), parsed_public.public_key());
+        assert_eq!(key.secret_key().public_key(), key.public_key());
+
+        // Node metadata
+        assert_eq!(path.len(), key.depth() as usize);
+        assert_eq!(path.last().copied().unwrap_or(0), key.index());
+        assert_eq!(key.index() >= HARDENED, key.is_hardened());
+
+        match &parent {
+            Some(parent) => {
+                assert_eq!(parent.fingerprint(), key.parent_fingerprint());
+                assert_eq!(Ok(key.clone()), parent.derive_child(key.index()));
+            }
+
+            None => assert_eq!([0u8; 4], key.parent_fingerprint()),
+        }
+
+        assert_eq!(key.fingerprint(), key.extended_public_key().fingerprint());
+        assert_eq!(key.chain_code(), parsed_public.chain_code());
+
+        parent.replace(key);
+    }
+}
+
+#[test]
+fn bip32_vector_1() {
+    check_vector(&seed(VECTOR_1_SEED), VECTOR_1);
+}
+
+#[test]
+fn bip32_vector_2() {
+    check_vector(&seed(VECTOR_2_SEED), VECTOR_2);
+}
+
+#[test]
+fn public_derivation() {
+    let master = ExtendedSecretKey::from_seed(&seed(VECTOR_2_SEED)).expect("Failed to create key");
+    let account = master
+        .derive_hardened(44)
+        .and_then(|k| k.derive_hardened(1179993420))
+        .and_then(|k| k.derive_hardened(0))
+        .expect("Failed to derive account");
+
+    // The receive addresses are derived without the secret keys
+    let xpub = ExtendedPublicKey::from(&account);
+    let receive = DerivationPath::from_str("m/0").expect("Invalid path");
+
+    for index in 0..8 {
+        let path = receive.extended(index);
+        let secret = account.derive_path(&path).expect("Failed to derive secret");
+        let public = xpub.derive_path(&path).expect("Failed to derive public");
+
+        assert_eq!(secret.extended_public_key(), public);
+        assert_eq!(secret.public_key(), public.public_key());
+        assert_eq!(secret.fingerprint(), public.fingerprint());
+        assert_eq!(secret.parent_fingerprint(), public.parent_fingerprint());
+        assert_eq!(5, public.depth());
+        assert_eq!(index, public.index());
+        assert!(!public.is_hardened());
+
+        let message = Message::new(format!("Receive #{index}"));
+        let signature = Signature::sign(&secret.secret_key(), &message);
+
+        assert_eq!(Ok(public.public_key()), signature.recover(&message));
+    }
+
+    // Hardened children require the secret key
+    assert_eq!(Err(Error::Bip32Error), xpub.derive_child(HARDENED));
+    assert_eq!(
+        Err(Error::Bip32Error),
+        xpub.derive_path(&DerivationPath::from_str("m/0/1'").expect("Invalid path"))
+    );
+
+    assert_eq!(
+        account.derive_child(HARDENED | 7),
+        account.derive_hardened(7)
+    );
+    assert!(account
+        .derive_hardened(7)
+        .expect("Failed to derive")
+        .is_hardened());
+    assert_eq!(
+        Err(Error::Bip32Error),
+        account.derive_hardened(HARDENED | 7)
+    );
+}
+
+#[test]
+fn mnemonic_derivation() {
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    let path = "m/44'/60'/0'/0/0";
+
+    let master = ExtendedSecretKey::from_mnemonic_phrase(phrase).expect("Failed to create key");
+    let key = master
+        .derive_path(&DerivationPath::from_str(path).expect("Invalid path"))
+        .expect("Failed to derive key");
+
+    let secret = SecretKey::new_from_mnemonic_phrase_with_path(phrase, path)
+        .expect("Failed to create secret key");
+
+    assert_eq!(secret, key.secret_key());
+    assert_eq!(
+        "30cc18506ed9d500fa348d1202bac14e9683b6d4cd7a02eb5357504d74ff2a19a8b672eb22c6509588424bab5c627515a9105b7ad25b7f948fcb5cd09448df5e",
+        key.public_key().to_string()
+    );
+
+    assert_eq!(
+        Err(Error::InvalidMnemonic),
+        ExtendedSecretKey::from_mnemonic_phrase("oblige salon price").map(|_| ())
+    );
+}
+
+#[test]
+fn invalid_extended_keys() {
+    let (_, xprv, xpub) = VECTOR_1[0];
+
+    // Public and secret keys aren't interchangeable
+    assert_eq!(
+        Err(Error::Bip32Error),
+        ExtendedSecretKey::from_str(xpub).map(|_| ())
+    );
+    assert_eq!(
+        Err(Error::Bip32Error),
+        ExtendedPublicKey::from_str(xprv).map(|_| ())
+    );
+
+    // Bad checksum
+    let mut corrupted = xprv.to_string();
+
+    corrupted.pop();
+    corrupted.push('j');
+
+    assert_eq!(
+        Err(Error::Bip32Error),
+        ExtendedSecretKey::from_str(&corrupted).map(|_| ())
+    );
+
+    // Seed too short
+    assert_eq!(
+        Err(Error::Bip32Error),
+        ExtendedSecretKey::from_seed(&[0u8; 8]).map(|_| ())
+    );
+
+    // The secrets aren't printed
+    let key = ExtendedSecretKey::from_str(xprv).expect("Failed to parse xprv");
+    let debug = format!("{key:?}");
+
+    assert!(!debug.contains(xprv));
+    assert!(!debug.contains(&key.secret_key().to_string()));
+}
+
+#[test]
+fn fuel_derivation_path() {
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    let master = ExtendedSecretKey::from_mnemonic_phrase(phrase).expect("Failed to create key");
+
+    let path = FuelDerivationPath::default();
+
+    assert_eq!("m/44'/1179993420'/0'/0/0", path.to_string());
+    assert_eq!(Ok(path), "m/44'/1179993420'/0'/0/0".parse());
+
+    let path = path
+        .with_account(3)
+        .and_then(|p| p.with_change(FuelDerivationPath::INTERNAL))
+        .and_then(|p| p.with_index(7))
+        .expect("Invalid path");
+
+    assert_eq!(Ok(path), FuelDerivationPath::new(3, 1, 7));
+    assert_eq!((3, 1, 7), (path.account(), path.change(), path.index()));
+    assert_eq!("m/44'/1179993420'/3'/1/7", path.to_string());
+    assert_eq!(Ok(path), path.to_string().parse());
+    assert_eq!(
+        DerivationPath::from_str("m/44'/1179993420'/3'/1/7").expect("Invalid path"),
+        DerivationPath::from(path)
+    );
+
+    // Same key as the hard-coded paths
+    let secret = SecretKey::new_from_mnemonic_phrase_with_path(phrase, &path.to_string())
+        .expect("Failed to create secret key");
+
+    assert_eq!(Ok(secret), path.derive(&master));
+
+    // Hardened fields
+    assert_eq!(
+        Err(Error::Bip32Error),
+        FuelDerivationPath::new(HARDENED, 0, 0)
+    );
+    assert_eq!(Err(Error::Bip32Error), path.with_change(HARDENED | 1));
+    assert_eq!(Err(Error::Bip32Error), path.with_index(HARDENED));
+
+    // Not a Fuel path
+    for invalid in [
+        "m/44'/60'/0'/0/0",
+        "m/49'/1179993420'/0'/0/0",
+        "m/44'/1179993420'/0/0/0",
+        "m/44'/1179993420'/0'/0'/0",
+        "m/44'/1179993420'/0'/0",
+        "m/44'/1179993420'/0'/0/0/0",
+        "m/44'/1179993420'/0'/0/x",
+    ] {
+        assert_eq!(
+            Err(Error::Bip32Error),
+            FuelDerivationPath::from_str(invalid),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn account_keys() {
+    let master = ExtendedSecretKey::from_seed(&seed(VECTOR_1_SEED)).expect("Failed to create key");
+    let account = FuelAccount::new(&master, 2).expect("Failed to derive account");
+
+    assert_eq!(2, account.account());
+    assert_eq!(3, account.extended_secret_key().depth());
+    assert_eq!(
+        Err(Error::Bip32Error),
+        FuelAccount::new(&master, HARDENED).map(|_| ())
+    );
+
+    let xpub = account.extended_public_key();
+
+    for change in [FuelDerivationPath::EXTERNAL, FuelDerivationPath::INTERNAL] {
+        let keys = account.chain_keys(change).expect("Failed to derive chain");
+
+        for (index, secret, public) in keys.take(10) {
+            let path = FuelDerivationPath::new(2, change, index).expect("Invalid path");
+
+            assert_eq!(Ok(path), account.path(change, index));
+            assert_eq!(Ok(secret), path.derive(&master));
+            assert_eq!(Ok((secret, public)), account.derive(change, index));
+            assert_eq!(secret.public_key(), public);
+
+            // Watch-only derivation
+            let watched = xpub
+                .derive_child(change)
+                .and_then(|k| k.derive_child(index))
+                .expect("Failed to derive public key");
+
+            assert_eq!(public, watched.public_key());
+        }
+    }
+
+    let indices: Vec<u32> = account
+        .keys()
+        .expect("Failed to derive chain")
+        .map(|(index, _, _)| index)
+        .take(5)
+        .collect();
+
+    assert_eq!(vec![0, 1, 2, 3, 4], indices);
+    assert_eq!(
+        account
+            .chain_keys(FuelDerivationPath::EXTERNAL)
+            .map(|k| k.take(3).collect::<Vec<_>>()),
+        account.keys().map(|k| k.take(3).collect::<Vec<_>>())
+    );
+
+    assert_eq!(Err(Error::Bip32Error), account.derive(HARDENED, 0));
+    assert_eq!(Err(Error::Bip32Error), account.derive(0, HARDENED));
+    assert_eq!(
+        Err(Error::Bip32Error),
+        account.chain_keys(HARDENED).map(|_| ())
+    );
+}
+
+#[test]
+fn account_discovery() {
+    let master = ExtendedSecretKey::from_seed(&seed(VECTOR_2_SEED)).expect("Failed to create key");
+
+    let public = |account, index| {
+        FuelDerivationPath::new(account, FuelDerivationPath::EXTERNAL, index)
+            .and_then(|path| path.derive(&master))
+            .map(|secret| secret.public_key())
+            .expect("Failed to derive key")
+    };
+
+    // The last used address of the third account is past the gap limit
+    let used: HashSet<PublicKey> = [public(0, 0), public(0, 3), public(1, 19), public(2, 20)]
+        .into_iter()
+        .collect();
+
+    let mut checked = 0;
+    let accounts = FuelAccount::discover(&master, FuelAccount::GAP_LIMIT, |public| {
+        checked += 1;
+
+        used.contains(public)
+    })
+    .expect("Failed to discover accounts");
+
+    assert_eq!(
+        vec![0, 1],
+        accounts
+            .iter()
+            .map(FuelAccount::account)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(1 + 20 + 20, checked);
+
+    // A larger gap limit finds the third account
+    let accounts = FuelAccount::discover(&master, 21, |public| used.contains(public))
+        .expect("Failed to discover accounts");
+
+    assert_eq!(3, accounts.len());
+    assert_eq!(Ok(accounts[2].clone()), FuelAccount::new(&master, 2));
+
+    // Unused wallet
+    let accounts = FuelAccount::discover(&master, FuelAccount::GAP_LIMIT, |_| false)
+        .expect("Failed to discover accounts");
+
+    assert!(accounts.is_empty());
+}
@@ -0,0 +1,94 @@
+use fuel_crypto::{CompressedPublicKey, Error, PublicKey, SecretKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::str::FromStr;
+
+#[test]
+fn compress_and_decompress() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        let public = SecretKey::random(rng).public_key();
+        let compressed = public.compress();
+
+        assert!(compressed.is_in_curve());
+        assert_eq!(public.as_ref()[..32], compressed[1..]);
+        assert_eq!(2 + (public[63] & 1), compressed[0]);
+
+        let decompressed = compressed.decompress().expect("Failed to decompress");
+
+        assert_eq!(public, decompressed);
+        assert_eq!(Ok(public), PublicKey::try_from(&compressed));
+        assert_eq!(compressed, CompressedPublicKey::from(&public));
+
+        let s = compressed.to_string();
+        let parsed = CompressedPublicKey::from_str(&s).expect("Failed to parse compressed key");
+
+        assert_eq!(compressed, parsed);
+        assert_eq!(
+            Ok(compressed),
+            CompressedPublicKey::from_str(&format!("{:#x}", compressed))
+        );
+        assert_eq!(
+            Ok(compressed),
+            CompressedPublicKey::try_from(&compressed[..])
+        );
+    }
+}
+
+#[test]
+fn invalid_compressed_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let public = SecretKey::random(rng).public_key();
+    let compressed = public.compress();
+
+    // Uncompressed and hybrid SEC1 tags aren't accepted
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0xff]
+        .iter()
+        .for_each(|flag| {
+            let mut invalid = *compressed;
+
+            invalid[0] = *flag;
+
+            // Safety: the curve is checked right after
+            let invalid = unsafe { CompressedPublicKey::from_bytes_unchecked(invalid) };
+
+            assert!(!invalid.is_in_curve());
+            assert_eq!(Err(Error::InvalidPublicKey), invalid.decompress());
+            assert_eq!(
+                Err(Error::InvalidPublicKey),
+                CompressedPublicKey::try_from(*invalid)
+            );
+        });
+
+    // `x` out of the field
+    let mut invalid = [0xff; CompressedPublicKey::LEN];
+
+    invalid[0] = 0x02;
+
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        CompressedPublicKey::try_from(invalid)
+    );
+
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        CompressedPublicKey::try_from(&compressed[1..])
+    );
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        CompressedPublicKey::from_str(&compressed.to_string()[2..])
+    );
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        CompressedPublicKey::from_str(&format!("g{}", &compressed.to_string()[1..]))
+    );
+
+    // The prefix `02` or `03` with a sign instead of the leading zero has the same value
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        CompressedPublicKey::from_str(&format!("+{}", &compressed.to_string()[1..]))
+    );
+}
@@ -0,0 +1,213 @@
+use fuel_crypto::ethereum::eip712::TypedData;
+use fuel_crypto::ethereum::{keccak256, Address};
+use fuel_crypto::fuel_types::Bytes32;
+use fuel_crypto::serde_json::{json, Value};
+use fuel_crypto::{Error, Message, SecretKey, Signature};
+
+use std::str::FromStr;
+
+// Check https://github.com/ethereum/EIPs/blob/master/assets/eip-712/Example.js
+const MAIL: &str = include_str!("fixtures/eip712-mail.json");
+
+// Produced with an independent implementation of `eth_signTypedData_v4`
+const ORDER: &str = include_str!("fixtures/eip712-order.json");
+
+fn bytes32(hex: &str) -> Bytes32 {
+    Bytes32::from_str(hex).expect("Invalid hex")
+}
+
+fn serde_json_value(json: &str) -> Value {
+    fuel_crypto::serde_json::from_str(json).expect("Invalid JSON")
+}
+
+#[test]
+fn mail() {
+    let data = TypedData::from_json(MAIL).expect("Invalid typed data");
+
+    assert_eq!(
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+        data.encode_type("Mail").expect("Failed to encode type")
+    );
+    assert_eq!(
+        bytes32("a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"),
+        data.type_hash("Mail").expect("Failed to hash type")
+    );
+    assert_eq!(
+        bytes32("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"),
+        data.domain_separator().expect("Failed to hash domain")
+    );
+
+    let value = serde_json_value(MAIL);
+
+    assert_eq!(
+        bytes32("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"),
+        data.hash_struct("Mail", &value["message"])
+            .expect("Failed to hash message")
+    );
+
+    let message = data.message().expect("Failed to hash typed data");
+
+    assert_eq!(
+        bytes32("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2").as_ref(),
+        message.as_ref()
+    );
+
+    // The signer of the example is `keccak256("cow")`
+    let secret = SecretKey::try_from(keccak256("cow")).expect("Invalid secret key");
+    let address =
+        Address::from_str("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").expect("Invalid address");
+
+    let r = bytes32("4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d");
+    let s = bytes32("07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562");
+    let signature = Signature::from_eth_parts(&r, &s, 28).expect("Invalid signature");
+
+    assert_eq!(signature, Signature::sign(&secret, &message));
+
+    let recover = signature.recover(&message).expect("Failed to recover PK");
+
+    assert_eq!(address, recover.eth_address());
+}
+
+#[test]
+fn builder() {
+    let person = [("name", "string"), ("wallet", "address")];
+    let mail = [("from", "Person"), ("to", "Person"), ("contents", "string")];
+
+    let domain = json!({
+        "name": "Ether Mail",
+        "version": "1",
+        "chainId": 1,
+        "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC",
+    });
+
+    let message = json!({
+        "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+        "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+        "contents": "Hello, Bob!",
+    });
+
+    // The domain type is inferred from the domain members
+    let data = TypedData::new("Mail", domain, message)
+        .with_type("Person", person)
+        .with_type("Mail", mail);
+
+    let expected = TypedData::from_json(MAIL).expect("Invalid typed data");
+
+    assert_eq!(expected.message(), data.message());
+    assert_eq!(expected.message(), Message::try_from(&data));
+}
+
+#[test]
+fn order() {
+    let data = TypedData::from_str(ORDER).expect("Invalid typed data");
+
+    assert_eq!(
+        "Order(Person maker,Person[] takers,uint128[2] amounts,int64 delta,bytes memo,bytes4 tag,bool settled,uint8[][] batches)Person(string name,address wallet)",
+        data.encode_type("Order").expect("Failed to encode type")
+    );
+    assert_eq!(
+        bytes32("1d6d37c8a9f51e5c47d64586fc88aeab272a05c41d92a93b8cb30729cb6bf1ef"),
+        data.domain_separator().expect("Failed to hash domain")
+    );
+    assert_eq!(
+        bytes32("681093eef34d683dd501e9d80cc152f1f118bd19f091e0b2c468f3173ea80947").as_ref(),
+        data.message().expect("Failed to hash typed data").as_ref()
+    );
+
+    // The inferred domain type includes the salt
+    let mut value = serde_json_value(ORDER);
+
+    value["types"]
+        .as_object_mut()
+        .expect("Invalid types")
+        .remove("EIP712Domain");
+
+    let inferred = TypedData::from_json(&value.to_string()).expect("Invalid typed data");
+
+    assert_eq!(data.message(), inferred.message());
+}
+
+#[test]
+fn invalid_typed_data() {
+    let value = serde_json_value(ORDER);
+
+    let cases: Vec<(&str, Value)> = vec![
+        (
+            "amounts",
+            json!(["340282366920938463463374607431768211456", 0]),
+        ),
+        ("amounts", json!([1, 2, 3])),
+        ("amounts", json!([-1, 2])),
+        ("amounts", json!([1.5, 2])),
+        ("amounts", json!(["0x", 2])),
+        ("delta", json!("-9223372036854775809")),
+        ("delta", json!("9223372036854775808")),
+        ("memo", json!("deadbeef")),
+        ("memo", json!("0xdeadbee")),
+        ("memo", json!("0x+f")),
+        ("memo", json!("0xde+f")),
+        ("tag", json!("0x6675656c00")),
+        ("settled", json!(1)),
+        ("batches", json!([[256]])),
+        ("maker", json!({ "name": "Cow" })),
+        (
+            "maker",
+            json!({ "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD827" }),
+        ),
+    ];
+
+    for (member, invalid) in cases {
+        let mut value = value.clone();
+
+        value["message"][member] = invalid;
+
+        let data = TypedData::from_json(&value.to_string()).expect("Invalid typed data");
+
+        assert_eq!(Err(Error::InvalidTypedData), data.message(), "{member}");
+    }
+
+    // Boundaries of the signed integers
+    for valid in [
+        json!("-9223372036854775808"),
+        json!(i64::MIN),
+        json!("0x7fffffffffffffff"),
+    ] {
+        let mut value = value.clone();
+
+        value["message"]["delta"] = valid;
+
+        let data = TypedData::from_json(&value.to_string()).expect("Invalid typed data");
+
+        assert!(data.message().is_ok());
+    }
+
+    // Unknown types
+    let mut unknown = value.clone();
+
+    unknown["types"]["Order"][1]["type"] = json!("Human[]");
+
+    let data = TypedData::from_json(&unknown.to_string()).expect("Invalid typed data");
+
+    assert_eq!(Err(Error::InvalidTypedData), data.message());
+
+    for ty in ["uint7", "uint264", "int0", "bytes0", "bytes33"] {
+        let mut value = value.clone();
+
+        value["types"]["Order"][3]["type"] = json!(ty);
+
+        let data = TypedData::from_json(&value.to_string()).expect("Invalid typed data");
+
+        assert_eq!(Err(Error::InvalidTypedData), data.message(), "{ty}");
+    }
+
+    // Malformed payloads
+    assert_eq!(Err(Error::InvalidTypedData), TypedData::from_json("[]"));
+    assert_eq!(
+        Err(Error::InvalidTypedData),
+        TypedData::from_json(&MAIL[1..])
+    );
+    assert_eq!(
+        Err(Error::InvalidTypedData),
+        TypedData::from_json(r#"{"types":{},"domain":{},"message":{}}"#)
+    );
+}
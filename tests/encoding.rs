@@ -0,0 +1,126 @@
+use fuel_crypto::{Error, PublicKey, SecretKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::str::FromStr;
+
+// Generated with `openssl ecparam -name secp256k1 -genkey -noout`, and converted with
+// `openssl ec` and `openssl pkcs8 -topk8 -nocrypt`
+const SECRET: &str = "6e5cc30c882634dd447213293b0d65f00a69ea4ed57232892b68c67c4e0c2ff6";
+const PUBLIC: &str = "d15a3ae22350111c4e06706ec0a661d52cb618ffeffae7996eed6c1ae64e588c2db413aad8ce390d372dd2ae46288b9e6b474ea684e6a0adc48062a47aba4c7d";
+
+const SEC1_DER: &[u8] = include_bytes!("fixtures/secp256k1-sec1.der");
+const SEC1_PEM: &str = include_str!("fixtures/secp256k1-sec1.pem");
+const PKCS8_DER: &[u8] = include_bytes!("fixtures/secp256k1-pkcs8.der");
+const PKCS8_PEM: &str = include_str!("fixtures/secp256k1-pkcs8.pem");
+const SPKI_DER: &[u8] = include_bytes!("fixtures/secp256k1-spki.der");
+const SPKI_PEM: &str = include_str!("fixtures/secp256k1-spki.pem");
+const SPKI_COMPRESSED_DER: &[u8] = include_bytes!("fixtures/secp256k1-spki-compressed.der");
+const PRIME256V1_PKCS8_PEM: &str = include_str!("fixtures/prime256v1-pkcs8.pem");
+
+#[test]
+fn openssl_fixtures() {
+    let secret = SecretKey::from_str(SECRET).expect("Invalid secret key");
+    let public = PublicKey::from_str(PUBLIC).expect("Invalid public key");
+
+    assert_eq!(public, secret.public_key());
+
+    assert_eq!(Ok(secret), SecretKey::from_sec1_der(SEC1_DER));
+    assert_eq!(Ok(secret), SecretKey::from_sec1_pem(SEC1_PEM));
+    assert_eq!(Ok(secret), SecretKey::from_pkcs8_der(PKCS8_DER));
+    assert_eq!(Ok(secret), SecretKey::from_pkcs8_pem(PKCS8_PEM));
+    assert_eq!(Ok(public), PublicKey::from_public_key_der(SPKI_DER));
+    assert_eq!(Ok(public), PublicKey::from_public_key_pem(SPKI_PEM));
+    assert_eq!(
+        Ok(public),
+        PublicKey::from_public_key_der(SPKI_COMPRESSED_DER)
+    );
+
+    // The encoding is byte-identical to OpenSSL
+    assert_eq!(SEC1_DER, secret.to_sec1_der().as_slice());
+    assert_eq!(SEC1_PEM, secret.to_sec1_pem().as_str());
+    assert_eq!(PKCS8_DER, secret.to_pkcs8_der().as_slice());
+    assert_eq!(PKCS8_PEM, secret.to_pkcs8_pem().as_str());
+    assert_eq!(SPKI_DER, public.to_public_key_der().as_slice());
+    assert_eq!(SPKI_PEM, public.to_public_key_pem());
+}
+
+#[test]
+fn encode_and_decode() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        assert_eq!(Ok(secret), SecretKey::from_sec1_der(&secret.to_sec1_der()));
+        assert_eq!(Ok(secret), SecretKey::from_sec1_pem(&secret.to_sec1_pem()));
+        assert_eq!(
+            Ok(secret),
+            SecretKey::from_pkcs8_der(&secret.to_pkcs8_der())
+        );
+        assert_eq!(
+            Ok(secret),
+            SecretKey::from_pkcs8_pem(&secret.to_pkcs8_pem())
+        );
+        assert_eq!(
+            Ok(public),
+            PublicKey::from_public_key_der(&public.to_public_key_der())
+        );
+        assert_eq!(
+            Ok(public),
+            PublicKey::from_public_key_pem(&public.to_public_key_pem())
+        );
+    }
+}
+
+#[test]
+fn invalid_documents() {
+    assert_eq!(
+        Err(Error::UnsupportedCurve),
+        SecretKey::from_pkcs8_pem(PRIME256V1_PKCS8_PEM)
+    );
+
+    // Mismatching PEM labels
+    assert_eq!(Err(Error::InvalidPem), SecretKey::from_sec1_pem(PKCS8_PEM));
+    assert_eq!(Err(Error::InvalidPem), SecretKey::from_pkcs8_pem(SEC1_PEM));
+    assert_eq!(
+        Err(Error::InvalidPem),
+        PublicKey::from_public_key_pem(PKCS8_PEM)
+    );
+    assert_eq!(
+        Err(Error::InvalidPem),
+        SecretKey::from_sec1_pem(&SEC1_PEM[1..])
+    );
+
+    // Truncated and mismatching DER documents
+    assert_eq!(
+        Err(Error::InvalidDer),
+        SecretKey::from_sec1_der(&SEC1_DER[..SEC1_DER.len() - 1])
+    );
+    assert_eq!(Err(Error::InvalidDer), SecretKey::from_pkcs8_der(SEC1_DER));
+    assert_eq!(Err(Error::InvalidDer), SecretKey::from_sec1_der(PKCS8_DER));
+    assert_eq!(
+        Err(Error::InvalidDer),
+        PublicKey::from_public_key_der(&SPKI_DER[1..])
+    );
+
+    // Embedded public key that doesn't match the secret key
+    let mut der = SEC1_DER.to_vec();
+    let last = der.len() - 1;
+
+    der[last] ^= 0x01;
+
+    assert_eq!(Err(Error::InvalidPublicKey), SecretKey::from_sec1_der(&der));
+
+    // Point not in the curve
+    let mut der = SPKI_DER.to_vec();
+    let last = der.len() - 1;
+
+    der[last] ^= 0x01;
+
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        PublicKey::from_public_key_der(&der)
+    );
+}
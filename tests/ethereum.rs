@@ -0,0 +1,187 @@
+use fuel_crypto::ethereum::{keccak256, Address};
+use fuel_crypto::fuel_types::Bytes32;
+use fuel_crypto::{Error, Message, SecretKey, Signature};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::str::FromStr;
+
+#[test]
+fn personal_sign() {
+    // Check https://web3js.readthedocs.io/en/v1.2.11/web3-eth-accounts.html#sign
+    let secret = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    let address = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    let message = "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655";
+    let signature = "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+
+    let secret = SecretKey::from_str(secret).expect("Invalid secret key");
+    let address = Address::from_str(address).expect("Invalid address");
+    let message = Bytes32::from_str(message).expect("Invalid message");
+    let signature: Vec<u8> = (0..signature.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&signature[i..i + 2], 16).expect("Invalid hex"))
+        .collect();
+
+    let public = secret.public_key();
+
+    assert_eq!(address, public.eth_address());
+    assert_eq!(*message, *Message::new_personal_sign("Some data"));
+
+    let message = Message::new_personal_sign(b"Some data");
+    let eth_signature = Signature::from_eth_bytes(&signature).expect("Invalid signature");
+
+    // Both RFC-6979 implementations produce the same signature
+    assert_eq!(eth_signature, Signature::sign(&secret, &message));
+    assert_eq!(signature, eth_signature.to_eth_bytes());
+    assert_eq!(Ok(public), eth_signature.recover_strict(&message));
+
+    // Multi-digit length prefix, check https://docs.ethers.org/v5/api/utils/hashing/#utils-hashMessage
+    let message = "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2";
+    let message = Bytes32::from_str(message).expect("Invalid message");
+
+    assert_eq!(*message, *Message::new_personal_sign("Hello World"));
+}
+
+#[test]
+fn eip155() {
+    // Check https://eips.ethereum.org/EIPS/eip-155#example
+    let secret = "4646464646464646464646464646464646464646464646464646464646464646";
+    let address = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    let message = "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53";
+    let r = "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276";
+    let s = "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    let secret = SecretKey::from_str(secret).expect("Invalid secret key");
+    let address = Address::from_str(address).expect("Invalid address");
+    let message = Bytes32::from_str(message).expect("Invalid message");
+    let r = Bytes32::from_str(r).expect("Invalid r");
+    let s = Bytes32::from_str(s).expect("Invalid s");
+
+    // Safety: the transaction signing hash is a keccak256 digest
+    let message = unsafe { Message::from_bytes_unchecked(*message) };
+    let signature = Signature::from_eth_parts(&r, &s, 37).expect("Invalid signature");
+
+    assert_eq!(address, Address::from(&secret.public_key()));
+    assert_eq!(signature, Signature::sign(&secret, &message));
+    assert_eq!(Ok(37), signature.eth_v(Some(1)));
+    assert_eq!(Ok(27), signature.eth_v(None));
+    assert_eq!(Ok(u64::MAX - 2), signature.eth_v(Some((u64::MAX - 36) / 2)));
+    assert_eq!(
+        Err(Error::InvalidChainId),
+        signature.eth_v(Some(u64::MAX / 2))
+    );
+    assert_eq!(Ok(secret.public_key()), signature.recover(&message));
+
+    // The recovery id is the same for every representation of `v`
+    for v in [0, 27, 37, 1_337 * 2 + 35, u64::MAX] {
+        assert_eq!(Ok(signature), Signature::from_eth_parts(&r, &s, v));
+    }
+
+    for v in [1, 28, 38, 1_337 * 2 + 36, u64::MAX - 1] {
+        assert_ne!(Ok(signature), Signature::from_eth_parts(&r, &s, v));
+    }
+}
+
+#[test]
+fn eth_bytes() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message =
+        Message::new_personal_sign(b"The journey of a thousand miles begins with one step.");
+
+    for _ in 0..100 {
+        let secret = SecretKey::random(rng);
+        let signature = Signature::sign(&secret, &message);
+
+        let bytes = signature.to_eth_bytes();
+
+        assert_eq!(signature.eth_v(None), Ok(bytes[64] as u64));
+        assert_eq!(&signature[..32], &bytes[..32]);
+        assert_eq!(signature[32] & 0x7f, bytes[32]);
+        assert_eq!(Ok(signature), Signature::from_eth_bytes(&bytes));
+        assert_eq!(
+            Ok(secret.public_key().eth_address()),
+            Signature::from_eth_bytes(&bytes)
+                .and_then(|s| s.recover(&message))
+                .map(|pk| pk.eth_address())
+        );
+    }
+
+    let secret = SecretKey::random(rng);
+    let bytes = Signature::sign(&secret, &message).to_eth_bytes();
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        Signature::from_eth_bytes(&bytes[..64])
+    );
+
+    for v in [2, 26, 29, 34] {
+        let mut bytes = bytes;
+
+        bytes[64] = v;
+
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            Signature::from_eth_bytes(&bytes)
+        );
+    }
+
+    // `s` in the upper half of the field can't be represented
+    let mut bytes = bytes;
+
+    bytes[32] |= 0x80;
+
+    assert_eq!(
+        Err(Error::NonCanonicalSignature),
+        Signature::from_eth_bytes(&bytes)
+    );
+}
+
+#[test]
+fn eip55() {
+    // Check https://eips.ethereum.org/EIPS/eip-55#test-cases
+    let addresses = [
+        "0x52908400098527886E0F7030069857D2E4169EE7",
+        "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+        "0xde709f2102306220921060314715629080e2fb77",
+        "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    for a in addresses {
+        let address = Address::from_str(a).expect("Invalid address");
+
+        assert_eq!(a, address.to_string());
+        assert_eq!(a.to_lowercase(), format!("{:#x}", address));
+        assert_eq!(Ok(address), Address::from_str(&a.to_lowercase()));
+        assert_eq!(Ok(address), Address::from_str(&a[2..].to_uppercase()));
+    }
+
+    // Mismatching checksum
+    assert_eq!(
+        Err(Error::InvalidAddress),
+        Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD")
+    );
+    assert_eq!(
+        Err(Error::InvalidAddress),
+        Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA")
+    );
+    assert_eq!(
+        Err(Error::InvalidAddress),
+        Address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg")
+    );
+
+    // A sign is accepted by `from_str_radix`, but isn't an hex digit
+    assert_eq!(
+        Err(Error::InvalidAddress),
+        Address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea+d")
+    );
+
+    assert_eq!(
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        format!("{:x}", keccak256([]))
+    );
+}
@@ -1,3 +1,4 @@
+use fuel_crypto::fuel_types::Bytes32;
 use fuel_crypto::*;
 
 #[test]
@@ -48,3 +49,257 @@ fn digest() {
 
     assert_eq!(digest, d);
 }
+
+fn surface<H>(input: &[&[u8]]) -> Bytes32
+where
+    H: CryptoHasher,
+{
+    let mut h = H::default();
+
+    input.iter().for_each(|i| h.input(i));
+
+    let digest = h.digest();
+
+    assert_eq!(digest, h.clone().finalize());
+    assert_eq!(digest, H::default().extend_chain(input.iter()).finalize());
+    assert_eq!(
+        digest,
+        input
+            .iter()
+            .fold(H::default(), |h, i| h.chain(i))
+            .finalize()
+    );
+    assert_eq!(digest, H::hash(input.concat()));
+    assert_eq!(*digest, *Message::new_with::<H, _>(input.concat()));
+    assert_eq!(*digest, *Message::from_hasher(h.clone()));
+
+    h.reset();
+
+    assert_eq!(H::hash([]), h.finalize());
+
+    digest
+}
+
+fn bytes32(hex: &str) -> Bytes32 {
+    hex.parse().expect("Invalid hex")
+}
+
+#[test]
+fn sha256() {
+    assert_eq!(
+        bytes32("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        Hasher::hash([])
+    );
+    assert_eq!(
+        bytes32("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        surface::<Hasher>(&[b"a", b"b", b"c"])
+    );
+    assert_eq!(Message::new("abc"), Message::new_with::<Hasher, _>("abc"));
+}
+
+#[test]
+fn sha512_256() {
+    assert_eq!(
+        bytes32("c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a"),
+        Sha512_256Hasher::hash([])
+    );
+    assert_eq!(
+        bytes32("53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"),
+        surface::<Sha512_256Hasher>(&[b"a", b"b", b"c"])
+    );
+}
+
+#[cfg(feature = "keccak")]
+#[test]
+fn keccak256() {
+    assert_eq!(
+        bytes32("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+        Keccak256Hasher::hash([])
+    );
+    assert_eq!(
+        bytes32("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        surface::<Keccak256Hasher>(&[b"a", b"b", b"c"])
+    );
+}
+
+#[cfg(feature = "blake2")]
+#[test]
+fn blake2b() {
+    assert_eq!(
+        bytes32("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"),
+        Blake2bHasher::hash([])
+    );
+    assert_eq!(
+        bytes32("bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"),
+        surface::<Blake2bHasher>(&[b"a", b"b", b"c"])
+    );
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn blake3() {
+    assert_eq!(
+        bytes32("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+        Blake3Hasher::hash([])
+    );
+    assert_eq!(
+        bytes32("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"),
+        surface::<Blake3Hasher>(&[b"a", b"b", b"c"])
+    );
+}
+
+fn hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("Invalid hex"))
+        .collect()
+}
+
+#[test]
+fn hmac() {
+    // Check https://www.rfc-editor.org/rfc/rfc4231#section-4
+    let vectors: [(Vec<u8>, &[u8], &str); 6] = [
+        (
+            vec![0x0b; 20],
+            b"Hi There",
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            b"Jefe".to_vec(),
+            b"what do ya want for nothing?",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        (
+            vec![0xaa; 20],
+            &[0xdd; 50],
+            "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+        ),
+        (
+            (1..=25).collect(),
+            &[0xcd; 50],
+            "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+        ),
+        (
+            vec![0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+        (
+            vec![0xaa; 131],
+            b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.",
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+        ),
+    ];
+
+    for (key, data, code) in vectors {
+        let code = bytes32(code);
+
+        assert_eq!(code, Hmac::mac(&key, data));
+        assert_eq!(
+            code,
+            Hmac::new(&key).extend_chain(data.chunks(7)).finalize()
+        );
+
+        let mut hmac = Hmac::new(&key);
+
+        data.chunks(3).for_each(|c| hmac.input(c));
+
+        assert_eq!(code, hmac.digest());
+        assert!(hmac.clone().verify(&code));
+
+        let mut invalid = code;
+
+        invalid[31] ^= 1;
+
+        assert!(!hmac.verify(&invalid));
+    }
+
+    // Truncated output
+    assert_eq!(
+        hex("a3b6167473100ee06e0c796c2955552b"),
+        Hmac::mac([0x0c; 20], "Test With Truncation")[..16]
+    );
+}
+
+#[test]
+fn hkdf() {
+    // Check https://www.rfc-editor.org/rfc/rfc5869#appendix-A
+    let vectors = [
+        (
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "000102030405060708090a0b0c",
+            "f0f1f2f3f4f5f6f7f8f9",
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        ),
+        (
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f",
+            "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+            "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        ),
+        (
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "",
+            "",
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        ),
+    ];
+
+    for (ikm, salt, info, prk, okm) in vectors {
+        let (ikm, salt, info, okm) = (hex(ikm), hex(salt), hex(info), hex(okm));
+
+        let hkdf = Hkdf::new(&salt, &ikm);
+
+        assert_eq!(bytes32(prk), hkdf.prk());
+
+        let mut output = vec![0u8; okm.len()];
+
+        hkdf.expand(&info, &mut output).expect("Failed to expand");
+
+        assert_eq!(okm, output);
+        assert_eq!(okm[..32], *hkdf.expand_bytes32(&info));
+
+        let hkdf = Hkdf::from_prk(bytes32(prk));
+
+        hkdf.expand(&info, &mut output).expect("Failed to expand");
+
+        assert_eq!(okm, output);
+    }
+
+    // The zeroed salt of the specification is the default
+    assert_eq!(
+        Hkdf::new([0u8; 32], "ikm").prk(),
+        Hkdf::new([], "ikm").prk()
+    );
+
+    let hkdf = Hkdf::new("salt", "ikm");
+    let mut output = vec![0u8; Hkdf::MAX_OUTPUT_LEN + 1];
+
+    assert!(hkdf
+        .expand("info", &mut output[..Hkdf::MAX_OUTPUT_LEN])
+        .is_ok());
+    assert_eq!(
+        Err(Error::InvalidOutputLength),
+        hkdf.expand("info", &mut output)
+    );
+}
+
+#[cfg(any(feature = "std", feature = "k256"))]
+#[test]
+fn hkdf_secret_key() {
+    let hkdf = Hkdf::new("salt", "ikm");
+
+    let secret = hkdf
+        .expand_secret_key("fuel")
+        .expect("Failed to derive secret key");
+
+    assert_eq!(*hkdf.expand_bytes32("fuel"), *secret);
+    assert_ne!(
+        secret,
+        hkdf.expand_secret_key("fuel-1")
+            .expect("Failed to derive secret key")
+    );
+}
@@ -0,0 +1,587 @@
+use fuel_crypto::ethereum::Address;
+use fuel_crypto::uuid::Uuid;
+use fuel_crypto::{
+    DirectoryKeystore, EncryptedKey, Error, JsonKeyId, JsonKeystore, Kdf, KeyId, Keystore,
+    LockableKeystore, Message, SecretKey, Signer,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, fs, thread};
+
+// Check https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/#test-vectors
+const PBKDF2: &str = include_str!("fixtures/web3-keystore-pbkdf2.json");
+
+// The scrypt vector of the specification uses `r = 1` with `n = 2^18`, out of the bounds of
+// RFC 7914. This one encrypts the same key with the light parameters of go-ethereum.
+const SCRYPT: &str = include_str!("fixtures/web3-keystore-scrypt.json");
+
+const PASSWORD: &str = "testpassword";
+const SECRET: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+// Cheap parameters for the tests
+const SCRYPT_TEST: Kdf = Kdf::Scrypt {
+    log_n: 10,
+    r: 8,
+    p: 1,
+};
+
+fn temp_path<R>(rng: &mut R) -> PathBuf
+where
+    R: Rng,
+{
+    env::temp_dir().join(format!("fuel-crypto-{:016x}.json", rng.gen::<u64>()))
+}
+
+fn temp_dir<R>(rng: &mut R) -> PathBuf
+where
+    R: Rng,
+{
+    env::temp_dir().join(format!("fuel-crypto-{:016x}", rng.gen::<u64>()))
+}
+
+#[test]
+fn test_vectors() {
+    let secret = SecretKey::from_str(SECRET).expect("Invalid secret key");
+
+    for (json, kdf) in [
+        (PBKDF2, Kdf::Pbkdf2 { c: 262144 }),
+        (SCRYPT, Kdf::SCRYPT_LIGHT),
+    ] {
+        let key = EncryptedKey::from_json(json).expect("Invalid encrypted key");
+
+        assert_eq!(kdf, key.kdf());
+        assert_eq!(None, key.address());
+        assert_eq!(
+            &Uuid::parse_str("3198bc9c-6672-5ab3-d995-4942343ae5b6").expect("Invalid uuid"),
+            key.id()
+        );
+        assert_eq!(Ok(secret), key.decrypt(PASSWORD));
+        assert_eq!(Err(Error::InvalidPassword), key.decrypt("testpasswore"));
+
+        // The serialized document is equivalent
+        assert_eq!(Ok(key.clone()), key.to_json().parse());
+    }
+}
+
+#[test]
+fn encrypt() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for kdf in [SCRYPT_TEST, Kdf::Pbkdf2 { c: 1024 }] {
+        let secret = SecretKey::random(rng);
+        let key = EncryptedKey::encrypt(rng, &secret, "password", kdf).expect("Failed to encrypt");
+
+        assert_eq!(kdf, key.kdf());
+        assert_eq!(Some(&secret.public_key().eth_address()), key.address());
+        assert_eq!(Ok(secret), key.decrypt("password"));
+        assert_eq!(Err(Error::InvalidPassword), key.decrypt("Password"));
+
+        let parsed = EncryptedKey::from_json(&key.to_json()).expect("Invalid encrypted key");
+
+        assert_eq!(key, parsed);
+        assert_eq!(Ok(secret), parsed.decrypt("password"));
+
+        // Fresh salt, initialization vector and id
+        let other =
+            EncryptedKey::encrypt(rng, &secret, "password", kdf).expect("Failed to encrypt");
+
+        assert_ne!(key, other);
+        assert_ne!(key.id(), other.id());
+    }
+
+    let secret = SecretKey::random(rng);
+
+    for kdf in [
+        Kdf::Pbkdf2 { c: 0 },
+        Kdf::Scrypt {
+            log_n: 10,
+            r: 0,
+            p: 1,
+        },
+    ] {
+        assert_eq!(
+            Err(Error::InvalidEncryptedKey),
+            EncryptedKey::encrypt(rng, &secret, "password", kdf)
+        );
+    }
+}
+
+#[test]
+fn invalid_encrypted_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let secret = SecretKey::random(rng);
+    let key = EncryptedKey::encrypt(rng, &secret, "password", Kdf::Pbkdf2 { c: 16 })
+        .expect("Failed to encrypt");
+
+    let value: Value = serde_json::from_str(&key.to_json()).expect("Invalid JSON");
+
+    let cases: Vec<(&str, &str, Value)> = vec![
+        ("", "version", 2.into()),
+        ("crypto", "cipher", "aes-128-cbc".into()),
+        ("crypto", "kdf", "argon2".into()),
+        ("crypto", "ciphertext", "0xabcd".into()),
+        ("crypto", "mac", "deadbeef".into()),
+        ("crypto", "mac", "not hex".into()),
+        ("kdfparams", "dklen", 16.into()),
+        ("kdfparams", "prf", "hmac-sha512".into()),
+        ("kdfparams", "c", (u32::MAX as u64 + 1).into()),
+        ("kdfparams", "c", 10_000_001.into()),
+        ("", "id", "3198bc9c".into()),
+        ("", "address", 1.into()),
+        (
+            "",
+            "address",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".into(),
+        ),
+    ];
+
+    for (object, member, invalid) in cases {
+        let mut value = value.clone();
+
+        match object {
+            "" => value[member] = invalid,
+            "crypto" => value["crypto"][member] = invalid,
+            _ => value["crypto"][object][member] = invalid,
+        }
+
+        assert_eq!(
+            Err(Error::InvalidEncryptedKey),
+            EncryptedKey::from_json(&value.to_string()),
+            "{member}"
+        );
+    }
+
+    // The scrypt cost must be a power of two
+    let mut value: Value = serde_json::from_str(SCRYPT).expect("Invalid JSON");
+
+    value["crypto"]["kdfparams"]["n"] = 262143.into();
+
+    assert_eq!(
+        Err(Error::InvalidEncryptedKey),
+        EncryptedKey::from_json(&value.to_string())
+    );
+
+    // Above the cost bounds
+    for (member, invalid) in [("n", 1u64 << 21), ("r", 33), ("p", 17)] {
+        let mut value = value.clone();
+
+        value["crypto"]["kdfparams"]["n"] = 262144.into();
+        value["crypto"]["kdfparams"][member] = invalid.into();
+
+        assert_eq!(
+            Err(Error::InvalidEncryptedKey),
+            EncryptedKey::from_json(&value.to_string()),
+            "{member}"
+        );
+    }
+
+    // The largest bounded cost
+    let mut bounded = value.clone();
+
+    bounded["crypto"]["kdfparams"]["n"] = (1u64 << 20).into();
+    bounded["crypto"]["kdfparams"]["r"] = 8.into();
+    bounded["crypto"]["kdfparams"]["p"] = 16.into();
+
+    assert!(EncryptedKey::from_json(&bounded.to_string()).is_ok());
+
+    // Out of the bounds of RFC 7914
+    value["crypto"]["kdfparams"]["n"] = 262144.into();
+    value["crypto"]["kdfparams"]["r"] = 1.into();
+
+    let unbounded = EncryptedKey::from_json(&value.to_string()).expect("Invalid encrypted key");
+
+    assert_eq!(Err(Error::InvalidEncryptedKey), unbounded.decrypt(PASSWORD));
+
+    // The `Crypto` member of older documents
+    let mut value: Value = serde_json::from_str(PBKDF2).expect("Invalid JSON");
+    let crypto = value
+        .as_object_mut()
+        .and_then(|v| v.remove("crypto"))
+        .expect("Invalid JSON");
+
+    value["Crypto"] = crypto;
+
+    assert!(EncryptedKey::from_json(&value.to_string()).is_ok());
+
+    // Mismatching address
+    let mut value: Value = serde_json::from_str(&key.to_json()).expect("Invalid JSON");
+    let other = SecretKey::random(rng).public_key().eth_address();
+
+    value["address"] = format!("{:x}", other).into();
+
+    let mismatch = EncryptedKey::from_json(&value.to_string()).expect("Invalid encrypted key");
+
+    assert_eq!(
+        Err(Error::InvalidEncryptedKey),
+        mismatch.decrypt("password")
+    );
+}
+
+#[test]
+fn keystore() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let path = temp_path(rng);
+    let secret = SecretKey::random(rng);
+    let address = secret.public_key().eth_address();
+
+    let keystore = JsonKeystore::create(&path, rng, &secret, "password", SCRYPT_TEST)
+        .expect("Failed to create keystore");
+
+    assert_eq!(&address, keystore.address());
+    assert_eq!(secret.public_key(), keystore.public_key());
+
+    // The file isn't overwritten
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        JsonKeystore::create(&path, rng, &secret, "password", SCRYPT_TEST).map(|_| ())
+    );
+
+    let opened = JsonKeystore::open(&path, "password").expect("Failed to open keystore");
+
+    assert_eq!(keystore.encrypted_key(), opened.encrypted_key());
+    assert_eq!(path, opened.path());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs::metadata(&path)
+            .expect("Missing file")
+            .permissions()
+            .mode();
+
+        assert_eq!(0o600, mode & 0o777);
+    }
+
+    let message = Message::new(b"Knowledge is of no value unless you put it into practice.");
+
+    for id in [JsonKeyId::from(address), JsonKeyId::from(*opened.id())] {
+        let signature = opened.sign(&id, &message).expect("Failed to sign");
+
+        assert_eq!(Ok(secret.public_key()), signature.recover(&message));
+        assert_eq!(
+            Some(secret.public_key()),
+            opened
+                .public(&id)
+                .expect("Infallible keystore")
+                .map(|pk| pk.into_owned())
+        );
+    }
+
+    let unknown = JsonKeyId::Address(Address::default());
+
+    assert_eq!(Err(Error::KeyNotFound), opened.sign(&unknown, &message));
+    assert!(opened
+        .secret(&JsonKeyId::Uuid(Uuid::nil()))
+        .expect("Infallible keystore")
+        .is_none());
+
+    assert_eq!(
+        Err(Error::InvalidPassword),
+        JsonKeystore::open(&path, "Password").map(|_| ())
+    );
+
+    fs::remove_file(&path).expect("Failed to remove keystore");
+
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        JsonKeystore::open(&path, "password").map(|_| ())
+    );
+}
+
+#[test]
+fn keystore_test_vector() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let path = temp_path(rng);
+
+    fs::write(&path, PBKDF2).expect("Failed to write keystore");
+
+    let keystore = JsonKeystore::open(&path, PASSWORD);
+
+    fs::remove_file(&path).expect("Failed to remove keystore");
+
+    let keystore = keystore.expect("Failed to open keystore");
+    let secret = SecretKey::from_str(SECRET).expect("Invalid secret key");
+
+    assert_eq!(&secret.public_key().eth_address(), keystore.address());
+}
+
+#[test]
+fn directory_keystore() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let path = temp_dir(rng);
+    let keystore =
+        DirectoryKeystore::open(&path, "password", SCRYPT_TEST).expect("Failed to open keystore");
+
+    assert!(keystore.list().is_empty());
+
+    let secret = SecretKey::random(rng);
+    let secret_p = SecretKey::random(rng);
+
+    let hash = keystore
+        .insert(rng, "alice", secret)
+        .expect("Failed to insert");
+
+    keystore
+        .insert(rng, "bob", secret_p)
+        .expect("Failed to insert");
+
+    for label in ["", ".hidden", "../alice", "alice/bob", "alice bob"] {
+        let other = SecretKey::random(rng);
+
+        assert_eq!(
+            Err(Error::InvalidKeyLabel),
+            keystore.insert(rng, label, other)
+        );
+    }
+
+    assert_eq!(
+        Err(Error::KeyAlreadyExists),
+        keystore.insert(rng, "carol", secret)
+    );
+    assert!(!keystore.key_path("carol").exists());
+
+    // Each key is a standalone keystore file
+    let file = JsonKeystore::open(keystore.key_path("alice"), "password")
+        .expect("Failed to open keystore");
+
+    assert_eq!(secret.public_key(), file.public_key());
+
+    // Files that aren't keys are ignored
+    fs::write(path.join("notes.txt"), "alice").expect("Failed to write file");
+
+    let message = Message::new(b"You have power over your mind - not outside events.");
+    let reopened =
+        DirectoryKeystore::open(&path, "password", SCRYPT_TEST).expect("Failed to open keystore");
+
+    assert_eq!(keystore.list(), reopened.list());
+
+    let signature = reopened
+        .sign(&KeyId::from(hash), &message)
+        .expect("Failed to sign");
+
+    assert_eq!(Ok(secret.public_key()), signature.recover(&message));
+
+    assert_eq!(
+        Err(Error::InvalidPassword),
+        DirectoryKeystore::open(&path, "Password", SCRYPT_TEST).map(|_| ())
+    );
+
+    // Dotted labels keep their own files
+    let dotted = ["dave.v1", "dave.v2"].map(|label| {
+        let other = SecretKey::random(rng);
+
+        keystore
+            .insert(rng, label, other)
+            .expect("Failed to insert");
+
+        (label, other.public_key())
+    });
+
+    let reopened =
+        DirectoryKeystore::open(&path, "password", SCRYPT_TEST).expect("Failed to open keystore");
+
+    for (label, public) in dotted {
+        assert!(path.join(format!("{label}.json")).exists());
+        assert!(reopened.list().contains(&(label.to_string(), public)));
+        assert_eq!(Ok(label.to_string()), keystore.remove(&KeyId::from(label)));
+    }
+
+    // Rename and remove are mirrored on the directory
+    assert_eq!(
+        Err(Error::InvalidKeyLabel),
+        keystore.rename(&KeyId::from("alice"), "../carol")
+    );
+    assert_eq!(
+        Ok("alice".to_string()),
+        keystore.rename(&KeyId::from("alice"), "carol")
+    );
+    assert!(!keystore.key_path("alice").exists());
+    assert!(keystore.key_path("carol").exists());
+
+    assert_eq!(
+        Ok("bob".to_string()),
+        keystore.remove(&KeyId::from(&secret_p.public_key()))
+    );
+    assert!(!keystore.key_path("bob").exists());
+
+    let reopened =
+        DirectoryKeystore::open(&path, "password", SCRYPT_TEST).expect("Failed to open keystore");
+
+    assert_eq!(
+        vec![("carol".to_string(), secret.public_key())],
+        reopened.list()
+    );
+
+    fs::remove_dir_all(&path).expect("Failed to remove keystore");
+}
+
+#[test]
+fn lockable_keystore() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = LockableKeystore::new(SCRYPT_TEST);
+    let message =
+        Message::new(b"The happiness of your life depends upon the quality of your thoughts.");
+
+    let secret = SecretKey::random(rng);
+    let secret_p = SecretKey::random(rng);
+
+    let alice = KeyId::from("alice");
+    let bob = KeyId::from("bob");
+
+    keystore
+        .insert(rng, "alice", secret, "password")
+        .expect("Failed to insert");
+
+    assert_eq!(
+        Err(Error::InvalidPassword),
+        keystore.insert(rng, "bob", secret_p, "Password")
+    );
+
+    keystore
+        .insert(rng, "bob", secret_p, "password")
+        .expect("Failed to insert");
+
+    // Locked: the public keys are available, but not the secrets
+    assert!(!keystore.is_unlocked());
+    assert_eq!(None, keystore.unlocked_for());
+    assert_eq!(2, keystore.list().len());
+    assert_eq!(
+        Some(secret.public_key()),
+        keystore
+            .public(&alice)
+            .expect("Failed to read")
+            .map(|pk| pk.into_owned())
+    );
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        keystore.secret(&alice).map(|s| s.map(|s| s.into_owned()))
+    );
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        keystore.sign(&alice, &message)
+    );
+
+    assert_eq!(
+        Err(Error::InvalidPassword),
+        keystore.unlock("Password", Duration::from_secs(60))
+    );
+    assert!(!keystore.is_unlocked());
+
+    keystore
+        .unlock("password", Duration::from_secs(60))
+        .expect("Failed to unlock");
+
+    assert!(keystore.is_unlocked());
+    assert!(keystore.unlocked_for() > Some(Duration::from_secs(50)));
+
+    let signature = keystore.sign(&alice, &message).expect("Failed to sign");
+
+    assert_eq!(Ok(secret.public_key()), signature.recover(&message));
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        keystore.sign(&KeyId::from("carol"), &message)
+    );
+
+    // Keys added while unlocked are available, and removed keys aren't
+    let secret_c = SecretKey::random(rng);
+
+    keystore
+        .insert(rng, "carol", secret_c, "password")
+        .expect("Failed to insert");
+    keystore
+        .sign(&KeyId::from(&secret_c.public_key()), &message)
+        .expect("Failed to sign");
+
+    assert_eq!(Ok("bob".to_string()), keystore.remove(&bob));
+    assert_eq!(Err(Error::KeyNotFound), keystore.sign(&bob, &message));
+
+    keystore.lock();
+
+    assert!(!keystore.is_unlocked());
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        keystore.sign(&alice, &message)
+    );
+
+    // Auto-relock
+    keystore
+        .unlock("password", Duration::from_millis(200))
+        .expect("Failed to unlock");
+
+    keystore.sign(&alice, &message).expect("Failed to sign");
+
+    thread::sleep(Duration::from_millis(300));
+
+    assert_eq!(
+        Err(Error::KeystoreNotAvailable),
+        keystore.sign(&alice, &message)
+    );
+    assert!(!keystore.is_unlocked());
+
+    // The secrets are zeroed on expiry, without an access to the keystore
+    keystore
+        .unlock("password", Duration::from_millis(200))
+        .expect("Failed to unlock");
+
+    assert!(format!("{keystore:?}").contains("decrypted: true"));
+
+    thread::sleep(Duration::from_millis(500));
+
+    assert!(format!("{keystore:?}").contains("decrypted: false"));
+
+    // Unbounded unlock
+    keystore
+        .unlock("password", Duration::MAX)
+        .expect("Failed to unlock");
+
+    assert!(keystore.is_unlocked());
+    assert_eq!(None, keystore.unlocked_for());
+}
+
+#[test]
+fn lockable_keystore_threads() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = Arc::new(LockableKeystore::new(SCRYPT_TEST));
+    let secret = SecretKey::random(rng);
+
+    keystore
+        .insert(rng, "alice", secret, "password")
+        .expect("Failed to insert");
+    keystore
+        .unlock("password", Duration::from_secs(60))
+        .expect("Failed to unlock");
+
+    let handles: Vec<_> = (0..8u8)
+        .map(|i| {
+            let keystore = Arc::clone(&keystore);
+
+            thread::spawn(move || {
+                let message = Message::new([i]);
+
+                keystore
+                    .sign(&KeyId::from("alice"), &message)
+                    .expect("Failed to sign")
+                    .recover(&message)
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let public = handle.join().expect("Thread panicked");
+
+        assert_eq!(Ok(secret.public_key()), public);
+    }
+}
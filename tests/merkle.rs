@@ -0,0 +1,183 @@
+use fuel_crypto::fuel_types::Bytes32;
+use fuel_crypto::merkle::binary::{self, MerkleProof, MerkleTree};
+use fuel_crypto::{Error, Hasher, Sha512_256Hasher};
+
+fn bytes32(hex: &str) -> Bytes32 {
+    hex.parse().expect("Invalid hex")
+}
+
+fn leaves(n: u64) -> impl Iterator<Item = [u8; 8]> {
+    (0..n).map(u64::to_be_bytes)
+}
+
+#[test]
+fn root() {
+    // Produced with an independent implementation of the RFC-6962 Merkle Tree Hash, where the
+    // leaves are the big-endian indexes
+    let vectors = [
+        (
+            0,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            1,
+            "3e7077fd2f66d689e0cee6a7cf5b37bf2dca7c979af356d0a31cbc5c85605c7d",
+        ),
+        (
+            2,
+            "a7d91894b61fbf46378d88e3e1b1f7aef39532c504b484bd31551d15e0a09dff",
+        ),
+        (
+            3,
+            "9b4965f8b220ba42f7039ad0781c966cf90bb1aea15a80586d634b322ab1f4ce",
+        ),
+        (
+            5,
+            "cb03def0c5b63bdecbed6cd863c8e61edab23e1ac7ab70281c699183cac2bef6",
+        ),
+        (
+            8,
+            "b15acd8b1ccf7a9b81c04f69b27e5cabd67e90be0e6ff6a4d1ed87004a4f0cc1",
+        ),
+        (
+            13,
+            "c715dfae59a927f96e6b6582d700ded79ada3e0612e9b873041ac49827c542fa",
+        ),
+        (
+            100,
+            "2891e78a4dc1677f965bd3e91dea9b59088e219becee08c6ee5d15414b446a7d",
+        ),
+    ];
+
+    for (n, root) in vectors {
+        let tree: MerkleTree = leaves(n).collect();
+
+        assert_eq!(n, tree.leaves_count());
+        assert_eq!(bytes32(root), tree.root(), "{n}");
+    }
+
+    // The root is available after every push
+    let mut tree = MerkleTree::<Hasher>::new();
+
+    for n in 0..100 {
+        assert_eq!(leaves(n).collect::<MerkleTree>().root(), tree.root());
+
+        tree.push(n.to_be_bytes());
+    }
+}
+
+#[test]
+fn prove() {
+    let mut tree = MerkleTree::<Hasher>::with_proof_index(9);
+
+    tree.extend(leaves(13));
+
+    let (root, proof) = tree.prove().expect("Failed to prove the leaf");
+
+    assert_eq!(
+        bytes32("c715dfae59a927f96e6b6582d700ded79ada3e0612e9b873041ac49827c542fa"),
+        root
+    );
+    assert_eq!(
+        &[
+            bytes32("4b7735f6f1a0cef18db371351b21e5fb694b89d3bd90731797ce797746c97dfc"),
+            bytes32("bb776a57e28a3bbc32b0cbd7c0e09d6ce4d3598d29a0ed3ec97f71e7343d0c47"),
+            bytes32("e1cf836347cbd326d966707cea8a13c8e1b6f82dc23549f1094e12b9da096dc6"),
+            bytes32("b15acd8b1ccf7a9b81c04f69b27e5cabd67e90be0e6ff6a4d1ed87004a4f0cc1"),
+        ],
+        proof.proof_set()
+    );
+    assert!(proof.verify::<Hasher, _>(&root, 9u64.to_be_bytes()));
+
+    for n in 1..40 {
+        for index in 0..n {
+            let mut tree = MerkleTree::<Hasher>::with_proof_index(index);
+
+            tree.extend(leaves(n));
+
+            let (root, proof) = tree.prove().expect("Failed to prove the leaf");
+            let data = index.to_be_bytes();
+
+            assert_eq!(tree.root(), root);
+            assert_eq!(index, proof.index());
+            assert_eq!(n, proof.leaves_count());
+            assert!(proof.verify::<Hasher, _>(&root, data));
+
+            // Tampered leaf, position or tree
+            let other = (index + 1).to_be_bytes();
+
+            assert!(!proof.verify::<Hasher, _>(&root, other));
+            assert!(!proof.verify::<Sha512_256Hasher, _>(&root, data));
+            assert!(!binary::verify::<Hasher, _>(&root, data, n, n, &proof));
+
+            if index + 1 < n {
+                assert!(!binary::verify::<Hasher, _>(
+                    &root,
+                    data,
+                    index + 1,
+                    n,
+                    &proof
+                ));
+            }
+
+            if !proof.is_empty() {
+                assert!(!binary::verify::<Hasher, _>(
+                    &root,
+                    data,
+                    index,
+                    n,
+                    &proof[1..]
+                ));
+            }
+
+            let mut extended = proof.to_vec();
+
+            extended.push(root);
+
+            assert!(!binary::verify::<Hasher, _>(
+                &root, data, index, n, &extended
+            ));
+        }
+    }
+}
+
+#[test]
+fn prove_with_hasher() {
+    let mut tree = MerkleTree::<Sha512_256Hasher>::with_proof_index(6);
+
+    assert!(tree.prove().is_none());
+
+    tree.extend(leaves(6));
+
+    assert!(tree.prove().is_none());
+
+    tree.extend(leaves(11).skip(6));
+
+    let (root, proof) = tree.prove().expect("Failed to prove the leaf");
+
+    assert_ne!(leaves(11).collect::<MerkleTree>().root(), root);
+    assert!(proof.verify::<Sha512_256Hasher, _>(&root, 6u64.to_be_bytes()));
+    assert!(MerkleTree::<Hasher>::new().prove().is_none());
+}
+
+#[test]
+fn proof_from_parts() {
+    let mut tree = MerkleTree::<Hasher>::with_proof_index(3);
+
+    tree.extend(leaves(7));
+
+    let (root, proof) = tree.prove().expect("Failed to prove the leaf");
+    let parsed = MerkleProof::new(3, 7, proof.proof_set()).expect("Invalid proof");
+
+    assert_eq!(proof, parsed);
+    assert!(parsed.verify::<Hasher, _>(&root, 3u64.to_be_bytes()));
+
+    assert_eq!(
+        Err(Error::InvalidMerkleProof),
+        MerkleProof::new(7, 7, proof.proof_set())
+    );
+    assert_eq!(
+        Err(Error::InvalidMerkleProof),
+        MerkleProof::new(0, u64::MAX, &[root; 65])
+    );
+}
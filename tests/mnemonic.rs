@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use coins_bip32::path::DerivationPath;
 use coins_bip39::{English, Mnemonic};
-use fuel_crypto::{FuelMnemonic, SecretKey};
+use fuel_crypto::{Error, ExtendedSecretKey, FuelMnemonic, MnemonicLanguage, SecretKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 type W = English;
 
@@ -48,3 +50,246 @@ fn random_mnemonic_phrase() {
     let _secret = SecretKey::new_from_mnemonic_phrase_with_path(&phrase, "m/44'/60'/0'/0/0")
         .expect("failed to create secret key from mnemonic phrase");
 }
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// Test vectors from BIP-39, as (phrase, passphrase, seed)
+const PASSPHRASE_VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "TREZOR",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    ),
+    (
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "TREZOR",
+        "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    ),
+    (
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        "TREZOR",
+        "c0c519bd0e91a2ed54357d9d1ebef6f5af218a153624cf4f2da911a0ed8f7a09e2ef61af0aca007096df430022f7a2b6fb91661a9589097069720d015e4e982f",
+    ),
+    // The phrase and the passphrase are normalized to NFKD
+    (
+        "あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あおぞら",
+        "㍍ガバヴァぱばぐゞちぢ十人十色",
+        "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55",
+    ),
+];
+
+// Phrases of the other wordlists, in NFC form, with their seed without passphrase
+const LANGUAGE_VECTORS: &[(MnemonicLanguage, &str, &str)] = &[
+    (
+        MnemonicLanguage::ChineseSimplified,
+        "的 三 欧 三 考 于 据 保 量 损 破 战",
+        "9859899437d054276ba8301d0a27b0c0c67c6e2863d68ed8d52e44c5ed9e0cc4132a5f6ba37c4ee8a2f2bbc498293a642c9ff497fff1f5f546cae2c165e0f089",
+    ),
+    (
+        MnemonicLanguage::ChineseTraditional,
+        "的 三 歐 三 考 於 據 保 量 損 破 戰",
+        "3fac393cf2327d761e8443b66f2c5bb22cc59278c5b906b07dfd0f8be91e56c7bc60038744b2a8d89844f8746686c32fbb6a9e195b5e1fe811c60dc050e8654b",
+    ),
+    (
+        MnemonicLanguage::Czech,
+        "abdikace bidlo obvykle bidlo kopnout bachor doma doprovod bojovat lobista kachna dokola",
+        "01bff33895d6c09654dfef1bd3d22eb42d7f00c03cd8076d4cd79052b3131f23b0beecb2c8598834d68dcbba6354f10f690f7e5da562a0be9ef9e86ed8d13c0d",
+    ),
+    (
+        MnemonicLanguage::Italian,
+        "abaco alogeno mitigare alogeno fenomeno affetto bravura bronzina ampio gonfio elaborato bottino",
+        "e26a889ebae217f1115abd8d324d850927af0af43b42ed4c333b8962e1088f8ee6a829628cdbb1c70a4fd691aa6adeb40e631fc8cb3aa44746c361ba34e8be21",
+    ),
+    (
+        MnemonicLanguage::Korean,
+        "가격 걱정 심부름 걱정 별도 갈색 기법 기운 경력 산길 미술 기념",
+        "c84d23b603720bc67db1b1f5f1cbfc82b760736ad8069bf283c8d5d2a5b1e2075e73208fbe8763500b572839ff3c7827917a7d8eec19b2732152f84b0ace5b70",
+    ),
+    (
+        MnemonicLanguage::Portuguese,
+        "abacate afivelar inativo afivelar donzela achatar barulho beber albergue exalar cuidado banir",
+        "21eae7e0861e0fb828e7b5727992a1efaa90b16a58e4474924b1f58472c362b84abbb9cba83ea4f32cef178a9bb74f73feaa7a5ac2ad5df39eb7772c4f3a3b31",
+    ),
+    (
+        MnemonicLanguage::Spanish,
+        "ábaco álbum líquido álbum espuma acudir bolero bosque amante gaita dictar boca",
+        "21d369cf994a9b2d99c938c979d9ca95ceeb7ac55589622bf57e2f53e7edf7688eb32a140ac9206dbf219376a8ffc7ecf4d642a88834a1dfb633d75a34180b60",
+    ),
+];
+
+#[test]
+fn mnemonic_passphrase() {
+    for (phrase, passphrase, seed) in PASSPHRASE_VECTORS {
+        let derived = FuelMnemonic::to_seed(phrase, passphrase).expect("Failed to derive seed");
+
+        assert_eq!(*seed, hex(&derived));
+
+        let master = ExtendedSecretKey::from_mnemonic_phrase_with_passphrase(phrase, passphrase)
+            .expect("Failed to create master key");
+
+        assert_eq!(Ok(master), ExtendedSecretKey::from_seed(&derived));
+    }
+
+    let (phrase, passphrase, _) = PASSPHRASE_VECTORS[0];
+    let path = "m/44'/1179993420'/0'/0/0";
+
+    let secret = SecretKey::new_from_mnemonic_phrase_with_passphrase(phrase, path, passphrase)
+        .expect("Failed to create secret key");
+
+    let mnemonic = Mnemonic::<W>::new_from_phrase(phrase).expect("Failed to create mnemonic");
+    let d = DerivationPath::from_str(path).expect("Failed to create derivation path");
+
+    assert_eq!(
+        Ok(secret),
+        SecretKey::new_from_mnemonic_with_passphrase(d.clone(), mnemonic.clone(), passphrase)
+    );
+
+    // The passphrase derives another wallet; an empty passphrase is the same as none
+    let unprotected = SecretKey::new_from_mnemonic_phrase_with_path(phrase, path)
+        .expect("Failed to create secret key");
+
+    assert_ne!(secret, unprotected);
+    assert_eq!(
+        Ok(unprotected),
+        SecretKey::new_from_mnemonic_phrase_with_passphrase(phrase, path, "")
+    );
+    assert_eq!(Ok(unprotected), SecretKey::new_from_mnemonic(d, mnemonic));
+}
+
+#[test]
+fn mnemonic_languages() {
+    for (language, phrase, seed) in LANGUAGE_VECTORS {
+        assert_eq!(Ok(*language), FuelMnemonic::validate(phrase));
+        assert_eq!(Ok(()), FuelMnemonic::validate_in(phrase, *language));
+
+        let derived = FuelMnemonic::to_seed(phrase, "").expect("Failed to derive seed");
+
+        assert_eq!(*seed, hex(&derived));
+        assert_eq!(Ok(derived), FuelMnemonic::to_seed_in(phrase, "", *language));
+    }
+
+    assert_eq!(
+        Ok(MnemonicLanguage::English),
+        FuelMnemonic::validate(PASSPHRASE_VECTORS[0].0)
+    );
+
+    // Japanese phrases may be separated by ASCII spaces
+    let (japanese, passphrase, seed) = PASSPHRASE_VECTORS[3];
+    let spaced = japanese.replace('\u{3000}', " ");
+
+    assert_eq!(
+        Ok(MnemonicLanguage::Japanese),
+        FuelMnemonic::validate(&spaced)
+    );
+    assert_eq!(
+        Ok(seed.to_string()),
+        FuelMnemonic::to_seed(&spaced, passphrase).map(|s| hex(&s))
+    );
+
+    // Any Unicode form of the words
+    let nfc = "amour troupeau couteau brèche gustatif tenaille exécuter capuche dicter lagune jaune cogner";
+    let nfd = nfc.replace('è', "e\u{300}").replace('é', "e\u{301}");
+
+    assert_eq!(Ok(MnemonicLanguage::French), FuelMnemonic::validate(nfc));
+    assert_eq!(Ok(MnemonicLanguage::French), FuelMnemonic::validate(&nfd));
+    assert_eq!(
+        "fdb3b22216c16c4eb236abfedd1c4e87b18b2833afbfe8e70589d8fcfd24318f129a74e404fe7bdc1bf754ab22b35d8c3160bb28dc2c5286304ddcd092293853",
+        hex(&FuelMnemonic::to_seed(nfc, "mot de passe").expect("Failed to derive seed"))
+    );
+    assert_eq!(
+        FuelMnemonic::to_seed(nfc, "mot de passe"),
+        FuelMnemonic::to_seed(&nfd, "mot de passe")
+    );
+
+    // Derivation from a non-English phrase
+    let (_, spanish, _) = LANGUAGE_VECTORS[6];
+    let path = "m/44'/1179993420'/0'/0/0";
+    let secret = SecretKey::new_from_mnemonic_phrase_with_path(spanish, path)
+        .expect("Failed to create secret key");
+    let master = ExtendedSecretKey::from_mnemonic_phrase(spanish).expect("Failed to create key");
+
+    assert_eq!(
+        Ok(secret),
+        master
+            .derive_path(&DerivationPath::from_str(path).expect("Invalid path"))
+            .map(|key| key.secret_key())
+    );
+}
+
+#[test]
+fn generate_mnemonic_languages() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for language in MnemonicLanguage::ALL {
+        for count in [12, 15, 18, 21, 24] {
+            let phrase = FuelMnemonic::generate_mnemonic_phrase_in(rng, count, language)
+                .expect("Failed to generate phrase");
+
+            assert_eq!(count, phrase.split_whitespace().count());
+            assert_eq!(Ok(()), FuelMnemonic::validate_in(&phrase, language));
+            assert!(FuelMnemonic::to_seed_in(&phrase, "", language).is_ok());
+        }
+    }
+
+    let japanese = FuelMnemonic::generate_mnemonic_phrase_in(rng, 12, MnemonicLanguage::Japanese)
+        .expect("Failed to generate phrase");
+
+    assert_eq!(11, japanese.matches('\u{3000}').count());
+    assert_eq!(
+        Err(Error::InvalidMnemonic),
+        FuelMnemonic::generate_mnemonic_phrase_in(rng, 13, MnemonicLanguage::Korean)
+    );
+}
+
+#[test]
+fn invalid_mnemonic_phrase() {
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    // Unknown word
+    let typo = phrase.replace("sausage", "sausag");
+
+    assert_eq!(
+        Err(Error::InvalidMnemonicWord(5)),
+        FuelMnemonic::validate(&typo)
+    );
+    assert_eq!(
+        Err(Error::InvalidMnemonicWord(5)),
+        SecretKey::new_from_mnemonic_phrase_with_path(&typo, "m/44'/60'/0'/0/0")
+    );
+
+    // Word of another wordlist
+    let mixed = phrase.replace("worth", "brèche");
+
+    assert_eq!(
+        Err(Error::InvalidMnemonicWord(6)),
+        FuelMnemonic::validate(&mixed)
+    );
+    assert_eq!(
+        Err(Error::InvalidMnemonicWord(0)),
+        FuelMnemonic::validate_in(phrase, MnemonicLanguage::French)
+    );
+
+    // Swapped words
+    let swapped = phrase.replace("thank year", "year thank");
+
+    assert_eq!(
+        Err(Error::InvalidMnemonicChecksum),
+        FuelMnemonic::validate(&swapped)
+    );
+    assert_eq!(
+        Err(Error::InvalidMnemonicChecksum),
+        FuelMnemonic::to_seed(&swapped, "TREZOR")
+    );
+    assert_eq!(
+        Err(Error::InvalidMnemonicChecksum),
+        ExtendedSecretKey::from_mnemonic_phrase(&swapped).map(|_| ())
+    );
+
+    // Unsupported number of words
+    let short = phrase.rsplit_once(' ').expect("Empty phrase").0;
+
+    assert_eq!(Err(Error::InvalidMnemonic), FuelMnemonic::validate(short));
+    assert_eq!(Err(Error::InvalidMnemonic), FuelMnemonic::validate(""));
+}
@@ -0,0 +1,152 @@
+#![cfg(any(feature = "std", feature = "k256"))]
+
+use fuel_crypto::fuel_types::Bytes32;
+use fuel_crypto::{Error, Message, SchnorrSignature, SecretKey, XOnlyPublicKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::str::FromStr;
+
+fn message(hex: &str) -> Message {
+    let message = Bytes32::from_str(hex).expect("Invalid message");
+
+    // Safety: test vectors are 32-byte digests
+    unsafe { Message::from_bytes_unchecked(*message) }
+}
+
+#[test]
+fn sign_and_verify() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = b"What is done out of love always takes place beyond good and evil.";
+    let message = Message::new(message);
+
+    for _ in 0..100 {
+        let secret = SecretKey::random(rng);
+        let public = XOnlyPublicKey::from(&secret);
+
+        assert!(public.is_in_curve());
+        assert_eq!(public, XOnlyPublicKey::from(&secret.public_key()));
+
+        let signature = SchnorrSignature::sign(&secret, &message);
+
+        signature
+            .verify(&public, &message)
+            .expect("Failed to verify signature");
+
+        let mut corrupted = signature;
+
+        corrupted.as_mut()[SchnorrSignature::LEN - 1] ^= 0x01;
+
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            corrupted.verify(&public, &message)
+        );
+    }
+}
+
+// https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+#[test]
+fn bip340_sign_vectors() {
+    let vectors = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+        ),
+        (
+            "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+            "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+        ),
+        (
+            "c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9",
+            "dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8",
+            "c87aa53824b4d7ae2eb035a2b5bbbccc080e76cdc6d1692c4b0b62d798e6d906",
+            "7e2d58d8b3bcdf1abadec7829054f90dda9805aab56c77333024b9d0a508b75c",
+            "5831aaeed7b44bb74e5eab94ba9d4294c49bcf2a60728d8b4c200f50dd313c1bab745879a5ad954a72c45a91c3a51d3c7adea98d82f8481e0e1e03674a6f3fb7",
+        ),
+        (
+            "0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710",
+            "25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "7eb0509757e246f19449885651611cb965ecc1a187dd51b64fda1edc9637d5ec97582b9cb13db3933705b32ba982af5af25fd78881ebb32771fc5922efc66ea3",
+        ),
+    ];
+
+    for (secret, public, aux_rand, m, signature) in vectors {
+        let secret = SecretKey::from_str(secret).expect("Invalid secret key");
+        let public = XOnlyPublicKey::from_str(public).expect("Invalid public key");
+        let aux_rand = Bytes32::from_str(aux_rand).expect("Invalid aux rand");
+        let m = message(m);
+        let signature = SchnorrSignature::from_str(signature).expect("Invalid signature");
+
+        assert_eq!(public, XOnlyPublicKey::from(&secret));
+        assert_eq!(
+            signature,
+            SchnorrSignature::sign_with_aux_rand(&secret, &m, &aux_rand)
+        );
+
+        signature
+            .verify(&public, &m)
+            .expect("Failed to verify signature");
+    }
+}
+
+// https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+#[test]
+fn bip340_verify_vectors() {
+    let m = "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+    let public = "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+
+    let vectors = [
+        // valid, `r` with leading zeroes
+        (
+            "d69c3509bb99e412e68b0fe8544e72837dfa30746d8be2aa65975f29d22dc7b9",
+            "4df3c3f68fcc83b27e9d42c90431a72499f17875c81a599b566c9889b9696703",
+            "00000000000000000000003b78ce563f89a0ed9414f5aa28ad0d96d6795f9c6376afb1548af603b3eb45c9f8207dee1060cb71c04e80f593060b07d28308d7f4",
+            None,
+        ),
+        // `has_even_y(R)` is false
+        (
+            public,
+            m,
+            "fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a14602975563cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2",
+            Some(Error::InvalidSignature),
+        ),
+        // negated message
+        (
+            public,
+            m,
+            "1fa62e331edbc21c394792d2ab1100a7b432b013df3f6ff4f99fcb33e0e1515f28890b3edb6e7189b630448b515ce4f8622a954cfe545735aaea5134fccdb2bd",
+            Some(Error::InvalidSignature),
+        ),
+        // negated `s` value
+        (
+            public,
+            m,
+            "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769961764b3aa9b2ffcb6ef947b6887a226e8d7c93e00c5ed0c1834ff0d0c2e6da6",
+            Some(Error::InvalidSignature),
+        ),
+    ];
+
+    for (public, m, signature, expected) in vectors {
+        let public = XOnlyPublicKey::from_str(public).expect("Invalid public key");
+        let signature = SchnorrSignature::from_str(signature).expect("Invalid signature");
+
+        assert_eq!(expected, signature.verify(&public, &message(m)).err());
+    }
+
+    // public key not on curve
+    let public = "eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34";
+
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        XOnlyPublicKey::from_str(public)
+    );
+}
@@ -0,0 +1,181 @@
+use fuel_crypto::fuel_types::{Bytes32, Bytes64};
+use fuel_crypto::secp256r1::{PublicKey, SecretKey, Signature};
+use fuel_crypto::{Error, Message};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::str::FromStr;
+
+#[test]
+fn recover_and_verify() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = b"Everything we hear is an opinion, not a fact.";
+    let message = Message::new(message);
+
+    for _ in 0..100 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        assert!(secret.is_in_field());
+        assert!(public.is_in_curve());
+
+        let signature = Signature::sign(&secret, &message);
+        let recover = signature.recover(&message).expect("Failed to recover PK");
+
+        assert_eq!(public, recover);
+
+        signature
+            .verify(&public, &message)
+            .expect("Failed to verify signature");
+    }
+}
+
+#[test]
+fn corrupted_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = b"The soul becomes dyed with the color of its thoughts.";
+    let message = Message::new(message);
+
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    let signature = Signature::sign(&secret, &message);
+
+    // Tamper, bit by bit, the signature and public key.
+    //
+    // The recover and verify operations should fail in all cases.
+    (0..Signature::LEN).for_each(|i| {
+        (0..7).fold(1u8, |m, _| {
+            let mut s = signature;
+
+            s.as_mut()[i] ^= m;
+
+            match s.recover(&message) {
+                Ok(pk) => assert_ne!(public, pk),
+                Err(Error::InvalidSignature | Error::NonCanonicalSignature) => (),
+                Err(e) => panic!("Unexpected error: {:?}", e),
+            }
+
+            assert!(s.verify(&public, &message).is_err());
+
+            m << 1
+        });
+    });
+
+    (0..PublicKey::LEN).for_each(|i| {
+        (0..7).fold(1u8, |m, _| {
+            let mut p = public;
+
+            p.as_mut()[i] ^= m;
+
+            assert!(signature.verify(&p, &message).is_err());
+
+            m << 1
+        });
+    });
+}
+
+// https://www.rfc-editor.org/rfc/rfc6979#appendix-A.2.5
+#[test]
+fn rfc6979_vector() {
+    let secret =
+        Bytes32::from_str("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+            .expect("Invalid secret");
+    let public = Bytes64::from_str("60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299")
+        .expect("Invalid public key");
+
+    // The `s` of the vector is normalized to lower-S
+    let expected = Bytes64::from_str("efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf37160834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9")
+        .expect("Invalid signature");
+
+    let secret = SecretKey::try_from(secret).expect("Secret not in the field");
+    let public = PublicKey::try_from(public).expect("Public key not in the curve");
+
+    assert_eq!(public, secret.public_key());
+
+    let message = Message::new(b"sample");
+    let signature = Signature::sign(&secret, &message);
+
+    let mut truncated = signature;
+
+    truncated.as_mut()[32] &= 0x7f;
+
+    assert_eq!(expected, Bytes64::from(truncated));
+    assert_eq!(Ok(public), signature.recover(&message));
+}
+
+#[test]
+fn upper_s_is_rejected() {
+    use p256::ecdsa::signature::hazmat::PrehashVerifier;
+    use p256::elliptic_curve::ops::Reduce;
+    use p256::elliptic_curve::point::AffineCoordinates;
+    use p256::elliptic_curve::PrimeField;
+    use p256::{ProjectivePoint, Scalar};
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    let signing_key =
+        p256::ecdsa::SigningKey::from_slice(secret.as_ref()).expect("Secret not in the field");
+    let d = **signing_key.as_nonzero_scalar();
+    let k = Scalar::from(0x4655454cu64);
+
+    // `s = 2^255 - 1` is in the upper half of the order, but still leaves the most significant
+    // bit free for the recovery id. Solve the digest so `(r, s)` is a valid ECDSA signature.
+    let mut s = [0xff; 32];
+    s[0] = 0x7f;
+    let s = Scalar::from_repr(s.into()).unwrap();
+
+    let r = Scalar::reduce_bytes(&(ProjectivePoint::GENERATOR * k).to_affine().x());
+    let z = s * k - r * d;
+
+    let mut bytes = [0u8; Signature::LEN];
+    bytes[..32].copy_from_slice(&r.to_repr());
+    bytes[32..].copy_from_slice(&s.to_repr());
+
+    let message = unsafe { Message::from_bytes_unchecked(z.to_repr().into()) };
+
+    // Sanity check: the upper-S signature is valid for plain ECDSA
+    let p256_signature = p256::ecdsa::Signature::from_slice(&bytes).expect("Invalid signature");
+    assert!(signing_key
+        .verifying_key()
+        .verify_prehash(&z.to_repr(), &p256_signature)
+        .is_ok());
+
+    for v in [0x00, 0x80] {
+        let mut signature = bytes;
+
+        signature[32] |= v;
+
+        let signature = unsafe { Signature::from_bytes_unchecked(signature) };
+
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            signature.recover(&message)
+        );
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            signature.verify(&public, &message)
+        );
+    }
+}
+
+#[test]
+fn invalid_keys() {
+    assert_eq!(
+        Err(Error::InvalidSecretKey),
+        SecretKey::try_from(Bytes32::zeroed())
+    );
+    assert_eq!(
+        Err(Error::InvalidSecretKey),
+        SecretKey::try_from(Bytes32::new([0xff; 32]))
+    );
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        PublicKey::try_from(Bytes64::zeroed())
+    );
+}
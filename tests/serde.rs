@@ -1,4 +1,4 @@
-use fuel_crypto::{Message, SecretKey, Signature};
+use fuel_crypto::{Message, SchnorrSignature, SecretKey, Signature, XOnlyPublicKey};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
@@ -18,6 +18,14 @@ fn serde() {
 
     assert_eq!(public, public_p);
 
+    let compressed = public.compress();
+    let compressed_p =
+        bincode::serialize(&compressed).expect("Failed to serialize compressed public");
+    let compressed_p =
+        bincode::deserialize(&compressed_p).expect("Failed to deserialize compressed public");
+
+    assert_eq!(compressed, compressed_p);
+
     let message = b"Two souls live in me, alas, Irreconcilable with one another.";
     let message = Message::new(message);
     let message_p = bincode::serialize(&message).expect("Failed to serialize message");
@@ -30,4 +38,80 @@ fn serde() {
     let signature_p = bincode::deserialize(&signature_p).expect("Failed to deserialize signature");
 
     assert_eq!(signature, signature_p);
+
+    let x_only = XOnlyPublicKey::from(&public);
+    let x_only_p = bincode::serialize(&x_only).expect("Failed to serialize x-only public");
+    let x_only_p = bincode::deserialize(&x_only_p).expect("Failed to deserialize x-only public");
+
+    assert_eq!(x_only, x_only_p);
+
+    let schnorr = SchnorrSignature::sign(&secret, &message);
+    let schnorr_p = bincode::serialize(&schnorr).expect("Failed to serialize schnorr signature");
+    let schnorr_p =
+        bincode::deserialize(&schnorr_p).expect("Failed to deserialize schnorr signature");
+
+    assert_eq!(schnorr, schnorr_p);
+
+    #[cfg(feature = "ed25519")]
+    {
+        use fuel_crypto::ed25519;
+
+        let secret = ed25519::SecretKey::random(rng);
+        let secret_p = bincode::serialize(&secret).expect("Failed to serialize ed25519 secret");
+        let secret_p =
+            bincode::deserialize(&secret_p).expect("Failed to deserialize ed25519 secret");
+
+        assert_eq!(secret, secret_p);
+
+        let public = secret.public_key();
+        let public_p = bincode::serialize(&public).expect("Failed to serialize ed25519 public");
+        let public_p =
+            bincode::deserialize(&public_p).expect("Failed to deserialize ed25519 public");
+
+        assert_eq!(public, public_p);
+
+        let signature = ed25519::Signature::sign(&secret, message);
+        let signature_p =
+            bincode::serialize(&signature).expect("Failed to serialize ed25519 signature");
+        let signature_p =
+            bincode::deserialize(&signature_p).expect("Failed to deserialize ed25519 signature");
+
+        assert_eq!(signature, signature_p);
+    }
+
+    #[cfg(feature = "ethereum")]
+    {
+        let address = public.eth_address();
+        let address_p = bincode::serialize(&address).expect("Failed to serialize address");
+        let address_p = bincode::deserialize(&address_p).expect("Failed to deserialize address");
+
+        assert_eq!(address, address_p);
+    }
+
+    #[cfg(feature = "secp256r1")]
+    {
+        use fuel_crypto::secp256r1;
+
+        let secret = secp256r1::SecretKey::random(rng);
+        let secret_p = bincode::serialize(&secret).expect("Failed to serialize secp256r1 secret");
+        let secret_p =
+            bincode::deserialize(&secret_p).expect("Failed to deserialize secp256r1 secret");
+
+        assert_eq!(secret, secret_p);
+
+        let public = secret.public_key();
+        let public_p = bincode::serialize(&public).expect("Failed to serialize secp256r1 public");
+        let public_p =
+            bincode::deserialize(&public_p).expect("Failed to deserialize secp256r1 public");
+
+        assert_eq!(public, public_p);
+
+        let signature = secp256r1::Signature::sign(&secret, &message);
+        let signature_p =
+            bincode::serialize(&signature).expect("Failed to serialize secp256r1 signature");
+        let signature_p =
+            bincode::deserialize(&signature_p).expect("Failed to deserialize secp256r1 signature");
+
+        assert_eq!(signature, signature_p);
+    }
 }
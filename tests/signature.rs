@@ -1,6 +1,61 @@
-use fuel_crypto::{Error, Message, PublicKey, SecretKey, Signature};
+use fuel_crypto::{DerSignature, Error, Message, PublicKey, SecretKey, Signature};
+use k256::elliptic_curve::ff::PrimeField;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
 use rand::rngs::StdRng;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
+
+use std::str::FromStr;
+
+// The recovery id takes the most significant bit of `s`, so the malleated `n - s` is
+// representable only if `s` is in the narrow range `(n - 2^255, n/2)`. Such a signature is crafted
+// with a chosen `s` just below `n/2`, solving the message for a random nonce.
+fn malleable_signature(rng: &mut StdRng) -> (Signature, PublicKey, Message) {
+    let secret = SecretKey::random(rng);
+    let d = Option::<Scalar>::from(Scalar::from_repr(*FieldBytes::from_slice(secret.as_ref())))
+        .expect("Invalid secret key");
+
+    let k = NonZeroScalar::random(&mut *rng);
+    let point = (ProjectivePoint::GENERATOR * *k).to_affine();
+    let point = point.to_encoded_point(false);
+
+    let x = FieldBytes::from_slice(point.x().expect("Not the identity"));
+    let r = Option::<Scalar>::from(Scalar::from_repr(*x)).expect("Unreduced x coordinate");
+    let v = point.y().expect("Not the identity")[31] & 1;
+
+    // `-1/2` is `(n - 1) / 2`
+    let half = -Option::<Scalar>::from(Scalar::from(2u64).invert()).expect("Invertible");
+    let s = half - Scalar::from(rng.gen::<u32>() as u64 + 1);
+    let z = s * *k - r * d;
+
+    let mut signature = [0u8; Signature::LEN];
+
+    signature[..32].copy_from_slice(&r.to_bytes());
+    signature[32..].copy_from_slice(&s.to_bytes());
+    signature[32] |= v << 7;
+
+    // Safety: the signature and message are checked by the recovery
+    let signature = unsafe { Signature::from_bytes_unchecked(signature) };
+    let message = unsafe { Message::from_bytes_unchecked(z.to_bytes().into()) };
+
+    (signature, secret.public_key(), message)
+}
+
+// Produce the malleated `(r, n - s)` signature, flipping the recovery id so it still recovers
+// the same public key
+fn malleate(signature: &Signature) -> Signature {
+    let mut malleated = *signature;
+
+    malleated.as_mut()[32] &= 0x7f;
+
+    let s = Option::<Scalar>::from(Scalar::from_repr(*FieldBytes::from_slice(&malleated[32..])))
+        .expect("Invalid s");
+
+    malleated.as_mut()[32..].copy_from_slice(&(-s).to_bytes());
+    malleated.as_mut()[32] |= (signature[32] & 0x80) ^ 0x80;
+
+    malleated
+}
 
 #[test]
 fn recover() {
@@ -40,6 +95,17 @@ fn verify() {
         signature
             .verify(&public, &message)
             .expect("Failed to verify signature");
+
+        // The same `r || s` with the other recovery id recovers another key
+        let mut flipped = signature;
+
+        flipped.as_mut()[32] ^= 0x80;
+
+        assert_ne!(Ok(public), flipped.recover(&message));
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            flipped.verify(&public, &message)
+        );
     }
 }
 
@@ -98,3 +164,209 @@ fn corrupted_signature() {
         });
     });
 }
+
+#[test]
+fn batch() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = b"He who has a why to live can bear almost any how.";
+    let message = Message::new(message);
+
+    let mut items: Vec<_> = (0..200)
+        .map(|_| {
+            let secret = SecretKey::random(rng);
+            let public = secret.public_key();
+            let signature = Signature::sign(&secret, &message);
+
+            (signature, public, message)
+        })
+        .collect();
+
+    let invalid = [3, 57, 120, 199];
+
+    // The recovery id of `120` is flipped, so its signature recovers another key
+    invalid.iter().for_each(|i| match i {
+        120 => items[*i].0.as_mut()[32] ^= 0x80,
+        _ => items[*i].0.as_mut()[Signature::LEN - 1] ^= 0x01,
+    });
+
+    let recover: Vec<_> = items.iter().map(|(s, _, m)| (*s, *m)).collect();
+    let recover = Signature::recover_batch(&recover);
+
+    assert_eq!(items.len(), recover.len());
+
+    items
+        .iter()
+        .zip(recover.iter())
+        .enumerate()
+        .for_each(|(i, ((_, public, _), pk))| match pk {
+            Ok(pk) if invalid.contains(&i) => assert_ne!(public, pk),
+            Ok(pk) => assert_eq!(public, pk),
+            Err(Error::InvalidSignature) => assert!(invalid.contains(&i)),
+            Err(e) => panic!("Unexpected error: {}", e),
+        });
+
+    let failed = Signature::verify_batch(&items).expect_err("Corrupted signatures should fail");
+
+    assert_eq!(&invalid[..], failed.as_slice());
+
+    let mut results = vec![Ok(()); items.len()];
+
+    Signature::verify_batch_into(&items[..3], &mut results[..3]);
+
+    assert!(results[..3].iter().all(|r| r.is_ok()));
+
+    Signature::verify_batch(&items[..3]).expect("Failed to verify batch");
+}
+
+#[test]
+fn der() {
+    // Produced with `openssl dgst -sha256 -sign` using the key of `fixtures/secp256k1-sec1.pem`
+    const PUBLIC: &str = "d15a3ae22350111c4e06706ec0a661d52cb618ffeffae7996eed6c1ae64e588c2db413aad8ce390d372dd2ae46288b9e6b474ea684e6a0adc48062a47aba4c7d";
+    const UPPER_S: &[u8] = include_bytes!("fixtures/secp256k1-signature-upper-s.der");
+    const LOWER_S: &[u8] = include_bytes!("fixtures/secp256k1-signature-lower-s.der");
+
+    let message = Message::new(b"The only true wisdom is in knowing you know nothing.");
+    let public = PublicKey::from_str(PUBLIC).expect("Invalid public key");
+
+    for der in [UPPER_S, LOWER_S] {
+        let signature = Signature::from_der(der, &public, &message).expect("Invalid signature");
+
+        assert_eq!(
+            public,
+            signature.recover(&message).expect("Failed to recover PK")
+        );
+
+        signature
+            .verify(&public, &message)
+            .expect("Failed to verify signature");
+    }
+
+    let signature = Signature::from_der(LOWER_S, &public, &message).expect("Invalid signature");
+
+    assert_eq!(
+        LOWER_S,
+        signature.to_der().expect("Failed to encode").as_ref()
+    );
+
+    // The upper-S signature is normalized when encoded back
+    let signature = Signature::from_der(UPPER_S, &public, &message).expect("Invalid signature");
+
+    assert_ne!(
+        UPPER_S,
+        signature.to_der().expect("Failed to encode").as_ref()
+    );
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+        let signature = Signature::sign(&secret, &message);
+
+        let der = signature.to_der().expect("Failed to encode");
+
+        assert!(der.len() <= DerSignature::MAX_LEN);
+        assert_eq!(Ok(signature), Signature::from_der(&der, &public, &message));
+
+        let other = SecretKey::random(rng).public_key();
+
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            Signature::from_der(&der, &other, &message)
+        );
+    }
+
+    // Malformed encodings
+    assert_eq!(
+        Err(Error::InvalidDer),
+        Signature::from_der(&LOWER_S[1..], &public, &message)
+    );
+    assert_eq!(
+        Err(Error::InvalidDer),
+        Signature::from_der(&LOWER_S[..LOWER_S.len() - 1], &public, &message)
+    );
+    assert_eq!(Err(Error::InvalidSignature), Signature::default().to_der());
+}
+
+#[test]
+fn malleability() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        let (signature, public, message) = malleable_signature(rng);
+
+        assert!(signature.is_canonical());
+        assert_eq!(Ok(public), signature.recover_strict(&message));
+        assert_eq!(Ok(()), signature.verify_strict(&public, &message));
+
+        // Flipping the recovery id keeps the signature canonical, but recovers another key
+        let mut flipped = signature;
+
+        flipped.as_mut()[32] ^= 0x80;
+
+        assert!(flipped.is_canonical());
+        assert_ne!(Ok(public), flipped.recover_strict(&message));
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            flipped.verify_strict(&public, &message)
+        );
+
+        let malleated = malleate(&signature);
+
+        assert_ne!(signature, malleated);
+        assert!(!malleated.is_canonical());
+
+        // The permissive recovery accepts the upper-S form
+        assert_eq!(Ok(public), malleated.recover(&message));
+
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            malleated.recover_strict(&message)
+        );
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            malleated.verify_strict(&public, &message)
+        );
+
+        let mut normalized = malleated;
+
+        normalized.normalize_s();
+
+        assert_eq!(signature, normalized);
+
+        normalized.normalize_s();
+
+        assert_eq!(signature, normalized);
+    }
+
+    // Components out of the field are rejected instead of recovered from a zeroed signature
+    let message = Message::new(b"Man is condemned to be free.");
+    let secret = SecretKey::random(rng);
+    let signature = Signature::sign(&secret, &message);
+
+    let mut zeroed_r = signature;
+    let mut zeroed_s = signature;
+    let mut overflown_r = signature;
+
+    zeroed_r.as_mut()[..32].fill(0);
+    zeroed_s.as_mut()[32..].fill(0);
+    overflown_r.as_mut()[..32].fill(0xff);
+
+    for invalid in [zeroed_r, zeroed_s, overflown_r, Signature::default()] {
+        let mut normalized = invalid;
+
+        normalized.normalize_s();
+
+        assert_eq!(invalid, normalized);
+        assert!(!invalid.is_canonical());
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            invalid.recover_strict(&message)
+        );
+        assert_eq!(
+            Err(Error::NonCanonicalSignature),
+            invalid.verify_strict(&secret.public_key(), &message)
+        );
+    }
+}
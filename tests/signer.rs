@@ -1,9 +1,13 @@
 use fuel_crypto::borrown::Borrown;
-use fuel_crypto::{Keystore, Message, SecretKey, Signer};
+use fuel_crypto::{
+    Error, KeyId, Keystore, MemoryKeystore, Message, OpaqueSigner, PublicKey, Seal, SealedKeystore,
+    SecretKey, Signature, Signer,
+};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
-use std::io;
+use std::sync::Arc;
+use std::{io, thread};
 
 #[derive(Debug, Default, Clone)]
 struct TestKeystore {
@@ -102,3 +106,215 @@ fn signer() {
         .verify(public.as_ref(), &message)
         .expect_err("Wrong key should fail verification");
 }
+
+#[test]
+fn memory_keystore() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = MemoryKeystore::new();
+
+    let secret = SecretKey::random(rng);
+    let secret_p = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    let hash = keystore.insert("alice", secret).expect("Failed to insert");
+    let hash_p = keystore.insert("bob", secret_p).expect("Failed to insert");
+
+    assert_eq!(public.hash(), hash);
+    assert_eq!(2, keystore.len());
+    assert_eq!(
+        Err(Error::KeyAlreadyExists),
+        keystore.insert("carol", secret)
+    );
+    assert_eq!(
+        Err(Error::KeyAlreadyExists),
+        keystore.insert("alice", SecretKey::random(rng))
+    );
+
+    let message = Message::new(b"The only true wisdom is in knowing you know nothing.");
+
+    // Both identifiers resolve to the same key
+    for id in [
+        KeyId::from("alice"),
+        KeyId::from(hash),
+        KeyId::from(&public),
+    ] {
+        let signature = keystore.sign(&id, &message).expect("Failed to sign");
+
+        assert_eq!(Ok(public), signature.recover(&message));
+        assert_eq!(
+            Some(public),
+            keystore
+                .public(&id)
+                .expect("Infallible keystore")
+                .map(|pk| pk.into_owned())
+        );
+    }
+
+    assert_eq!(
+        vec![
+            ("alice".to_string(), public),
+            ("bob".to_string(), secret_p.public_key())
+        ],
+        keystore.list()
+    );
+
+    // Rename
+    assert_eq!(
+        Err(Error::KeyAlreadyExists),
+        keystore.rename(&KeyId::from("alice"), "bob")
+    );
+    assert_eq!(
+        Ok("alice".to_string()),
+        keystore.rename(&KeyId::from(hash), "carol")
+    );
+    assert_eq!(
+        Ok("carol".to_string()),
+        keystore.rename(&KeyId::from("carol"), "carol")
+    );
+    assert!(!keystore.contains(&KeyId::from("alice")));
+    assert_eq!(
+        Some("carol".to_string()),
+        keystore.label(&KeyId::from(hash))
+    );
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        keystore.sign(&KeyId::from("alice"), &message)
+    );
+
+    // Remove
+    assert_eq!(Ok("bob".to_string()), keystore.remove(&KeyId::from(hash_p)));
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        keystore.remove(&KeyId::from("bob"))
+    );
+    assert_eq!(1, keystore.len());
+
+    // The label of a removed key can be reused
+    keystore.insert("bob", secret_p).expect("Failed to insert");
+
+    assert_eq!(
+        Ok("carol".to_string()),
+        keystore.remove(&KeyId::from("carol"))
+    );
+    assert_eq!(Ok("bob".to_string()), keystore.remove(&KeyId::from("bob")));
+    assert!(keystore.is_empty());
+}
+
+#[test]
+fn memory_keystore_threads() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let keystore = Arc::new(MemoryKeystore::new());
+    let message = Message::new(b"Waste no more time arguing what a good man should be. Be one.");
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let keystore = Arc::clone(&keystore);
+            let secret = SecretKey::random(rng);
+
+            thread::spawn(move || {
+                let label = format!("key-{i}");
+                let hash = keystore
+                    .insert(label.as_str(), secret)
+                    .expect("Failed to insert");
+
+                let signature = keystore
+                    .sign(&KeyId::from(label), &message)
+                    .expect("Failed to sign");
+
+                assert_eq!(Ok(secret.public_key()), signature.recover(&message));
+
+                hash
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let hash = handle.join().expect("Thread panicked");
+
+        assert!(keystore.contains(&KeyId::from(hash)));
+    }
+
+    assert_eq!(8, keystore.len());
+}
+
+// Keystore that can be read only by the signers of the crate
+struct VaultKeystore {
+    keys: Vec<(SecretKey, PublicKey)>,
+}
+
+impl SealedKeystore for VaultKeystore {
+    type Error = Error;
+    type KeyId = usize;
+
+    fn sealed_secret(
+        &self,
+        id: &usize,
+        _seal: Seal,
+    ) -> Result<Option<Borrown<'_, SecretKey>>, Error> {
+        Ok(self
+            .keys
+            .get(*id)
+            .map(|(secret, _)| Borrown::Borrowed(secret)))
+    }
+
+    fn sealed_public(&self, id: &usize) -> Result<Option<Borrown<'_, PublicKey>>, Error> {
+        Ok(self
+            .keys
+            .get(*id)
+            .map(|(_, public)| Borrown::Borrowed(public)))
+    }
+}
+
+// Untrusted code, with access to the signer handle only
+fn plugin<K>(signer: OpaqueSigner<K>, id: &K::KeyId, message: &Message) -> Result<bool, K::Error>
+where
+    K: SealedKeystore,
+{
+    let public = signer.public(id)?;
+    let signature = signer.sign(id, message)?;
+
+    Ok(signature.verify(&public, message).is_ok())
+}
+
+#[test]
+fn opaque_signer() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let message = Message::new(b"Very little is needed to make a happy life.");
+
+    let secret = SecretKey::random(rng);
+    let vault = VaultKeystore {
+        keys: vec![(secret, secret.public_key())],
+    };
+
+    let signer = OpaqueSigner::new(vault);
+
+    assert_eq!(Ok(secret.public_key()), signer.public(&0));
+    assert_eq!(
+        Ok(Signature::sign(&secret, &message)),
+        signer.sign(&0, &message)
+    );
+    assert_eq!(Err(Error::KeyNotFound), signer.sign(&1, &message));
+    assert_eq!(Err(Error::KeyNotFound), signer.public(&1));
+    assert_eq!(Ok(true), plugin(signer, &0, &message));
+
+    // Any keystore can be sealed, and shared between the handles
+    let keystore = MemoryKeystore::new();
+    let hash = keystore.insert("alice", secret).expect("Failed to insert");
+    let signer = OpaqueSigner::new(Arc::new(keystore));
+
+    assert_eq!(
+        Ok(Signature::sign(&secret, &message)),
+        signer.sign(&KeyId::from(hash), &message)
+    );
+    assert_eq!(
+        Ok(true),
+        plugin(signer.clone(), &KeyId::from("alice"), &message)
+    );
+    assert_eq!(
+        Err(Error::KeyNotFound),
+        plugin(signer, &KeyId::from("bob"), &message)
+    );
+}
@@ -0,0 +1,325 @@
+use fuel_crypto::fuel_types::Bytes32;
+use fuel_crypto::merkle::sparse::{
+    MemoryStorage, Node, SparseMerkleProof, SparseMerkleTree, Storage, PLACEHOLDER,
+};
+use fuel_crypto::{borrown::Borrown, Error, Hasher};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+type Tree = SparseMerkleTree<MemoryStorage>;
+
+fn key(i: u64) -> Bytes32 {
+    Hasher::hash(i.to_be_bytes())
+}
+
+fn value(i: u64) -> String {
+    format!("value {i}")
+}
+
+fn tree(n: u64) -> Tree {
+    let mut tree = Tree::new(MemoryStorage::new());
+
+    for i in 0..n {
+        tree.update(&key(i), value(i)).expect("Failed to update");
+    }
+
+    tree
+}
+
+fn tree_root(n: u64) -> Bytes32 {
+    tree(n).root()
+}
+
+// Root of a set of leaves, from the definition of the tree
+fn reference_root(leaves: &BTreeMap<Bytes32, Vec<u8>>) -> Bytes32 {
+    fn subtree(leaves: &[(&Bytes32, &Vec<u8>)], depth: usize) -> Bytes32 {
+        match leaves {
+            [] => PLACEHOLDER,
+            [(key, value)] => Node::Leaf {
+                key: **key,
+                value: Hasher::hash(value),
+            }
+            .hash::<Hasher>(),
+            _ => {
+                let (left, right): (Vec<_>, Vec<_>) = leaves
+                    .iter()
+                    .partition(|(k, _)| k[depth / 8] & (0x80 >> (depth % 8)) == 0);
+
+                Node::Internal {
+                    left: subtree(&left, depth + 1),
+                    right: subtree(&right, depth + 1),
+                }
+                .hash::<Hasher>()
+            }
+        }
+    }
+
+    subtree(&leaves.iter().collect::<Vec<_>>(), 0)
+}
+
+#[test]
+fn root() {
+    // Produced with an independent implementation of the tree
+    let vectors = [
+        (
+            1,
+            "280d9777da617ff31241bc25038655219e54b82575efd40d5bab4dcdab080979",
+        ),
+        (
+            2,
+            "6f198c3e6fbc44bc0d50480293aa8e5d01fe5c29b541e5b51866465532b85b68",
+        ),
+        (
+            3,
+            "a0e293da6b001bbd6c1134b082cc40d9c761bb14492befd673f92ad9f7bd054f",
+        ),
+        (
+            10,
+            "983e88ba078ce5d60891af95aa780f17f19d7705b9dab4e5e299dd8e0a927c25",
+        ),
+        (
+            100,
+            "48dd30088e893a6535921b8a6b2448f511c886bd344cc7b969a5fab3485b884d",
+        ),
+    ];
+
+    assert_eq!(PLACEHOLDER, tree(0).root());
+
+    for (n, root) in vectors {
+        let expected = Bytes32::from_str(root).expect("Invalid hex");
+
+        assert_eq!(expected, tree(n).root(), "{n}");
+
+        // The root is independent of the insertion order
+        let mut tree = Tree::new(MemoryStorage::new());
+
+        for i in (0..n).rev() {
+            tree.update(&key(i), value(i)).expect("Failed to update");
+        }
+
+        assert_eq!(expected, tree.root(), "{n}");
+    }
+
+    // Keys diverging at the last bit
+    let mut tree = Tree::new(MemoryStorage::new());
+    let mut last = Bytes32::zeroed();
+
+    last[31] = 1;
+
+    tree.update(&Bytes32::zeroed(), "a")
+        .expect("Failed to update");
+    tree.update(&last, "b").expect("Failed to update");
+
+    assert_eq!(
+        Bytes32::from_str("34880ccbca38fa4f619fe7a076848d9503a57200038d267c448999d9e644b083"),
+        Ok(tree.root())
+    );
+
+    tree.delete(&last).expect("Failed to delete");
+
+    assert_eq!(
+        Node::Leaf {
+            key: Bytes32::zeroed(),
+            value: Hasher::hash("a")
+        }
+        .hash::<Hasher>(),
+        tree.root()
+    );
+    assert_eq!(1, tree.storage().len());
+}
+
+#[test]
+fn update_delete() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let mut tree = Tree::new(MemoryStorage::new());
+    let mut leaves = BTreeMap::new();
+
+    for _ in 0..1000 {
+        // Small key space, to update and delete existing keys
+        let key = key(rng.gen_range(0..200));
+
+        if rng.gen_bool(0.3) {
+            tree.delete(&key).expect("Failed to delete");
+            leaves.remove(&key);
+        } else {
+            let value: [u8; 8] = rng.gen();
+
+            tree.update(&key, value).expect("Failed to update");
+            leaves.insert(key, value.to_vec());
+        }
+
+        assert_eq!(reference_root(&leaves), tree.root());
+    }
+
+    for (key, value) in leaves.iter() {
+        assert_eq!(
+            Some(Hasher::hash(value)),
+            tree.value_hash(key).expect("Failed to read")
+        );
+    }
+
+    // Updating with the same value is a no-op
+    let (key, value) = leaves.iter().next().expect("Empty tree");
+    let root = tree.root();
+    let len = tree.storage().len();
+
+    tree.update(key, value).expect("Failed to update");
+
+    assert_eq!(root, tree.root());
+    assert_eq!(len, tree.storage().len());
+
+    // Deleting an absent key is a no-op
+    tree.delete(&Bytes32::zeroed()).expect("Failed to delete");
+
+    assert_eq!(root, tree.root());
+    assert_eq!(
+        None,
+        tree.value_hash(&Bytes32::zeroed()).expect("Failed to read")
+    );
+
+    // The storage doesn't keep the nodes of the previous roots
+    for key in leaves.keys() {
+        tree.delete(key).expect("Failed to delete");
+    }
+
+    assert_eq!(PLACEHOLDER, tree.root());
+    assert!(tree.storage().is_empty());
+}
+
+#[test]
+fn load() {
+    let tree = tree(50);
+    let root = tree.root();
+
+    let mut loaded = Tree::load(tree.into_storage(), root);
+
+    assert_eq!(
+        Some(Hasher::hash(value(7))),
+        loaded.value_hash(&key(7)).expect("Failed to read")
+    );
+
+    loaded.delete(&key(49)).expect("Failed to delete");
+
+    assert_eq!(tree_root(49), loaded.root());
+
+    // Missing nodes
+    let missing = Tree::load(MemoryStorage::new(), root);
+
+    assert_eq!(Err(Error::InvalidMerkleTree), missing.value_hash(&key(7)));
+    assert_eq!(
+        Err(Error::InvalidMerkleTree),
+        missing.prove(&key(7)).map(|_| ())
+    );
+}
+
+#[test]
+fn proof() {
+    let tree = tree(100);
+    let root = tree.root();
+    let previous = tree_root(99);
+
+    for i in 0..200 {
+        let key = key(i);
+        let proof = tree.prove(&key).expect("Failed to prove");
+
+        let bytes = proof.to_bytes();
+        let parsed = SparseMerkleProof::from_bytes(&bytes).expect("Invalid proof");
+
+        assert_eq!(proof, parsed);
+
+        if i < 100 {
+            assert!(parsed.verify_inclusion::<Hasher, _>(&root, &key, value(i)));
+            assert!(!parsed.verify_inclusion::<Hasher, _>(&root, &key, value(i + 1)));
+            assert!(!parsed.verify_exclusion::<Hasher>(&root, &key));
+        } else {
+            assert!(parsed.verify_exclusion::<Hasher>(&root, &key));
+            assert!(!parsed.verify_inclusion::<Hasher, _>(&root, &key, value(i)));
+        }
+
+        assert!(!parsed.verify_exclusion::<Hasher>(&previous, &key));
+    }
+
+    // The proof of a key doesn't prove its sibling leaf
+    let proof = tree.prove(&key(0)).expect("Failed to prove");
+
+    assert!(!proof.verify_inclusion::<Hasher, _>(&root, &key(1), value(1)));
+
+    // The empty tree proves the exclusion of every key
+    let empty = Tree::new(MemoryStorage::new());
+    let proof = empty.prove(&key(0)).expect("Failed to prove");
+
+    assert_eq!(&[0, 0, 0], proof.to_bytes().as_slice());
+    assert!(proof.verify_exclusion::<Hasher>(&PLACEHOLDER, &key(0)));
+}
+
+#[test]
+fn invalid_proof() {
+    let tree = tree(100);
+    let bytes = tree.prove(&key(0)).expect("Failed to prove").to_bytes();
+
+    let mut cases = vec![
+        vec![],
+        vec![2, 0, 0],
+        vec![0, 0],
+        vec![0, 0, 1],
+        vec![1; 66],
+        bytes[..bytes.len() - 1].to_vec(),
+        [bytes.as_slice(), &[0]].concat(),
+    ];
+
+    // More siblings than the depth of the tree
+    cases.push([&[0, 1, 1], [0u8; 33].as_slice()].concat());
+
+    // Sibling marked as present, but empty
+    cases.push([&[0, 0, 1, 0x80], [0u8; 32].as_slice()].concat());
+
+    // Non-empty padding of the bitmask
+    cases.push([&[0, 0, 1, 0x40], [1u8; 32].as_slice()].concat());
+
+    for bytes in cases {
+        assert_eq!(
+            Err(Error::InvalidMerkleProof),
+            SparseMerkleProof::from_bytes(&bytes),
+            "{bytes:?}"
+        );
+    }
+}
+
+#[derive(Default)]
+struct CountingStorage {
+    nodes: MemoryStorage,
+    writes: usize,
+}
+
+impl Storage for CountingStorage {
+    type Error = Error;
+
+    fn get(&self, hash: &Bytes32) -> Result<Option<Borrown<'_, Node>>, Error> {
+        self.nodes.get(hash)
+    }
+
+    fn insert(&mut self, hash: &Bytes32, node: &Node) -> Result<(), Error> {
+        self.writes += 1;
+        self.nodes.insert(hash, node)
+    }
+
+    fn remove(&mut self, hash: &Bytes32) -> Result<(), Error> {
+        self.nodes.remove(hash)
+    }
+}
+
+#[test]
+fn storage() {
+    let mut tree = SparseMerkleTree::<CountingStorage>::new(CountingStorage::default());
+
+    for i in 0..10 {
+        tree.update(&key(i), value(i)).expect("Failed to update");
+    }
+
+    assert_eq!(tree_root(10), tree.root());
+    assert!(tree.storage().writes > 10);
+}
@@ -0,0 +1,220 @@
+use fuel_crypto::secp256r1::webauthn::{Assertion, RelyingParty};
+use fuel_crypto::secp256r1::{PublicKey, SecretKey};
+use fuel_crypto::{Error, Hasher, Message};
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature as P256Signature, SigningKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const RP_ID: &str = "fuel.network";
+const ORIGIN: &str = "https://app.fuel.network";
+const RP: RelyingParty = RelyingParty::new(RP_ID, ORIGIN);
+
+// Base64url encoding of a `Message`, as performed by the browser
+fn encode_challenge(challenge: &Message) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    challenge
+        .chunks(3)
+        .flat_map(|c| {
+            let n = c.iter().fold(0u32, |n, b| n << 8 | *b as u32) << (8 * (3 - c.len()));
+
+            (0..=c.len()).map(move |i| ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char)
+        })
+        .collect()
+}
+
+fn authenticator_data(rp_id: &str, flags: u8) -> Vec<u8> {
+    let mut data = Hasher::hash(rp_id).to_vec();
+
+    data.push(flags);
+    data.extend_from_slice(&7u32.to_be_bytes());
+
+    data
+}
+
+fn client_data(ty: &str, challenge: &str) -> Vec<u8> {
+    client_data_with_origin(ty, challenge, ORIGIN)
+}
+
+fn client_data_with_origin(ty: &str, challenge: &str, origin: &str) -> Vec<u8> {
+    format!(
+        r#"{{"type":"{ty}","challenge":"{challenge}","origin":"{origin}","crossOrigin":false}}"#
+    )
+    .into_bytes()
+}
+
+// Sign as an authenticator: ASN.1 DER over `authenticatorData || sha256(clientDataJSON)`,
+// without normalizing `s`
+fn sign(secret: &SecretKey, authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+    let key = SigningKey::from_slice(secret.as_ref()).expect("Invalid secret");
+
+    let mut data = authenticator_data.to_vec();
+
+    data.extend_from_slice(Hasher::hash(client_data_json).as_ref());
+
+    let signature: P256Signature = key.sign(&data);
+
+    signature.to_der().as_bytes().to_vec()
+}
+
+#[test]
+fn verify_and_recover() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let challenge = Message::new(b"I think, therefore I am.");
+
+    let authenticator_data = authenticator_data(RP_ID, 0x05);
+    let client_data_json = client_data("webauthn.get", &encode_challenge(&challenge));
+    let assertion = Assertion::new(&authenticator_data, &client_data_json);
+
+    for _ in 0..50 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        let der = sign(&secret, &authenticator_data, &client_data_json);
+
+        let signature = assertion
+            .verify_der(&der, &public, &RP, &challenge, true)
+            .expect("Failed to verify assertion");
+
+        let recover = assertion
+            .recover(signature, &RP, &challenge, true)
+            .expect("Failed to recover PK");
+
+        assert_eq!(public, recover);
+
+        let candidates = assertion
+            .candidates_der(&der, &RP, &challenge, true)
+            .expect("Failed to recover PK candidates");
+
+        assert!(candidates.contains(&public));
+        assert_ne!(candidates[0], candidates[1]);
+
+        assertion
+            .verify(signature, &public, &RP, &challenge, true)
+            .expect("Failed to verify signature");
+
+        let other = SecretKey::random(rng).public_key();
+
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            assertion.verify_der(&der, &other, &RP, &challenge, true)
+        );
+    }
+}
+
+#[test]
+fn invalid_assertion() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let secret = SecretKey::random(rng);
+    let public: PublicKey = secret.public_key();
+
+    let challenge = Message::new(b"The unexamined life is not worth living.");
+    let encoded = encode_challenge(&challenge);
+
+    let authenticator_data = authenticator_data(RP_ID, 0x05);
+    let client_data_json = client_data("webauthn.get", &encoded);
+
+    let cases: Vec<(Vec<u8>, Vec<u8>, bool, Error)> = vec![
+        // Wrong relying party
+        (
+            self::authenticator_data("fuel.sh", 0x05),
+            client_data_json.clone(),
+            false,
+            Error::InvalidAuthenticatorData,
+        ),
+        // User not present
+        (
+            self::authenticator_data(RP_ID, 0x04),
+            client_data_json.clone(),
+            false,
+            Error::InvalidAuthenticatorData,
+        ),
+        // User not verified
+        (
+            self::authenticator_data(RP_ID, 0x01),
+            client_data_json.clone(),
+            true,
+            Error::InvalidAuthenticatorData,
+        ),
+        // Truncated authenticator data
+        (
+            authenticator_data[..36].to_vec(),
+            client_data_json.clone(),
+            false,
+            Error::InvalidAuthenticatorData,
+        ),
+        // Wrong origin
+        (
+            authenticator_data.clone(),
+            client_data_with_origin("webauthn.get", &encoded, "https://evil.network"),
+            false,
+            Error::InvalidClientData,
+        ),
+        // Expected origin as a prefix
+        (
+            authenticator_data.clone(),
+            client_data_with_origin("webauthn.get", &encoded, &format!("{ORIGIN}.evil")),
+            false,
+            Error::InvalidClientData,
+        ),
+        // Registration instead of assertion
+        (
+            authenticator_data.clone(),
+            client_data("webauthn.create", &encoded),
+            false,
+            Error::InvalidClientData,
+        ),
+        // Wrong challenge
+        (
+            authenticator_data.clone(),
+            client_data("webauthn.get", &encode_challenge(&Message::new(b"Wrong"))),
+            false,
+            Error::InvalidClientData,
+        ),
+        // Expected challenge as a prefix
+        (
+            authenticator_data.clone(),
+            client_data("webauthn.get", &format!("{encoded}A")),
+            false,
+            Error::InvalidClientData,
+        ),
+    ];
+
+    for (authenticator_data, client_data_json, user_verification, error) in cases {
+        let assertion = Assertion::new(&authenticator_data, &client_data_json);
+        let der = sign(&secret, &authenticator_data, &client_data_json);
+
+        assert_eq!(
+            Err(error),
+            assertion.verify_der(&der, &public, &RP, &challenge, user_verification)
+        );
+        assert_eq!(
+            Err(error),
+            assertion.candidates_der(&der, &RP, &challenge, user_verification)
+        );
+    }
+
+    // Client data that doesn't match the signed one
+    let der = sign(&secret, &authenticator_data, &client_data_json);
+    let mut tampered = client_data_json.clone();
+
+    tampered.pop();
+
+    let assertion = Assertion::new(&authenticator_data, &tampered);
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        assertion.verify_der(&der, &public, &RP, &challenge, false)
+    );
+
+    // Malformed DER
+    let assertion = Assertion::new(&authenticator_data, &client_data_json);
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        assertion.verify_der(&der[1..], &public, &RP, &challenge, false)
+    );
+}